@@ -64,24 +64,55 @@ async fn get_operations() -> Json<Vec<OperationInfo>> {
             name: "url_encode".to_string(),
             category: "Encoding".to_string(),
             description: "URL encode data".to_string(),
-            parameters: vec![],
+            parameters: vec![
+                ParameterInfo {
+                    name: "encode_set".to_string(),
+                    param_type: "string".to_string(),
+                    description: "component, path, query, fragment, form (space as +) or all".to_string(),
+                    required: false,
+                    default_value: Some("component".to_string()),
+                },
+            ],
         },
         OperationInfo {
             name: "url_decode".to_string(),
             category: "Encoding".to_string(),
             description: "URL decode data".to_string(),
-            parameters: vec![],
+            parameters: vec![
+                ParameterInfo {
+                    name: "plus_as_space".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decode + as a space (form encoding)".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "recursive".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Keep decoding until the output stops changing".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
         },
         OperationInfo {
             name: "html_encode".to_string(),
             category: "Encoding".to_string(),
             description: "HTML entity encode".to_string(),
-            parameters: vec![],
+            parameters: vec![
+                ParameterInfo {
+                    name: "mode".to_string(),
+                    param_type: "string".to_string(),
+                    description: "minimal (markup characters), non_ascii (also non-ASCII as numeric) or all (every character as named/numeric)".to_string(),
+                    required: false,
+                    default_value: Some("minimal".to_string()),
+                },
+            ],
         },
         OperationInfo {
             name: "html_decode".to_string(),
             category: "Encoding".to_string(),
-            description: "HTML entity decode".to_string(),
+            description: "Decode HTML named and numeric character references".to_string(),
             parameters: vec![],
         },
         OperationInfo {
@@ -93,73 +124,2424 @@ async fn get_operations() -> Json<Vec<OperationInfo>> {
         OperationInfo {
             name: "hex_decode".to_string(),
             category: "Encoding".to_string(),
-            description: "Decode data from hexadecimal".to_string(),
+            description: "Decode data from hexadecimal (spaces, colons, 0x and \\x prefixes allowed)".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "hexdump".to_string(),
+            category: "Encoding".to_string(),
+            description: "Create an xxd-style hexdump with offsets and ASCII column".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "width".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Bytes per line".to_string(),
+                    required: false,
+                    default_value: Some("16".to_string()),
+                },
+                ParameterInfo {
+                    name: "group".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Bytes per group (0 for no grouping)".to_string(),
+                    required: false,
+                    default_value: Some("2".to_string()),
+                },
+                ParameterInfo {
+                    name: "uppercase".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Use uppercase hex digits".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "start_offset".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Value added to displayed offsets".to_string(),
+                    required: false,
+                    default_value: Some("0".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "from_hexdump".to_string(),
+            category: "Encoding".to_string(),
+            description: "Parse an xxd, hexdump -C or Wireshark hex dump back into data".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output as utf8, lossy, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "encode_text".to_string(),
+            category: "Encoding".to_string(),
+            description: "Encode text into a character encoding (UTF-16, Latin-1, Windows-125x, EBCDIC, Shift-JIS, ...)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "encoding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Target encoding, e.g. utf-16le, utf-32be, iso-8859-2, windows-1251, cp037, shift_jis, gbk".to_string(),
+                    required: false,
+                    default_value: Some("utf-16le".to_string()),
+                },
+                ParameterInfo {
+                    name: "bom".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Prepend a byte order mark (UTF encodings only)".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "strict".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Fail on unencodable characters instead of replacing them with '?'".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output as hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "decode_text".to_string(),
+            category: "Encoding".to_string(),
+            description: "Decode bytes from a character encoding into text".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "encoding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Source encoding, or auto to detect a byte order mark".to_string(),
+                    required: false,
+                    default_value: Some("auto".to_string()),
+                },
+                ParameterInfo {
+                    name: "strict".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Fail on malformed input instead of inserting U+FFFD".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input given as hex, base64, latin1 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        // Hashing operations
+        OperationInfo {
+            name: "md5".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate MD5 hash".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "sha1".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate SHA-1 hash".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "sha256".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate SHA-256 hash".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "sha512".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate SHA-512 hash".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "sha3".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate SHA-3 hash".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Digest length: 224, 256, 384 or 512".to_string(),
+                    required: false,
+                    default_value: Some("256".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "keccak".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate original Keccak hash (as used by Ethereum)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Digest length: 224, 256, 384 or 512".to_string(),
+                    required: false,
+                    default_value: Some("256".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "shake".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate SHAKE extendable-output hash".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "variant".to_string(),
+                    param_type: "number".to_string(),
+                    description: "SHAKE variant: 128 or 256".to_string(),
+                    required: false,
+                    default_value: Some("256".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Output length in bits".to_string(),
+                    required: false,
+                    default_value: Some("512".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "blake2b".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate BLAKE2b hash".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Digest length in bits (8-512)".to_string(),
+                    required: false,
+                    default_value: Some("512".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "blake2s".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate BLAKE2s hash".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Digest length in bits (8-256)".to_string(),
+                    required: false,
+                    default_value: Some("256".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "blake3".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate BLAKE3 hash".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Output length in bits".to_string(),
+                    required: false,
+                    default_value: Some("256".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "ripemd".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate RIPEMD hash".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Digest length: 128, 160, 256 or 320".to_string(),
+                    required: false,
+                    default_value: Some("160".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "whirlpool".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate Whirlpool hash".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "sm3".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate SM3 hash".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "xxhash".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate xxHash (non-cryptographic)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "variant".to_string(),
+                    param_type: "string".to_string(),
+                    description: "xxh32, xxh64, xxh3_64 or xxh3_128".to_string(),
+                    required: false,
+                    default_value: Some("xxh64".to_string()),
+                },
+                ParameterInfo {
+                    name: "seed".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Seed value".to_string(),
+                    required: false,
+                    default_value: Some("0".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "murmurhash3".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate MurmurHash3 (non-cryptographic)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Hash length: 32 or 128 (x64)".to_string(),
+                    required: false,
+                    default_value: Some("32".to_string()),
+                },
+                ParameterInfo {
+                    name: "seed".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Seed value".to_string(),
+                    required: false,
+                    default_value: Some("0".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "fnv1a".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate FNV-1a hash (non-cryptographic)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Hash length: 32 or 64".to_string(),
+                    required: false,
+                    default_value: Some("64".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "generate_all_hashes".to_string(),
+            category: "Hashing".to_string(),
+            description: "Calculate every supported hash and checksum in a single pass".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: table or json".to_string(),
+                    required: false,
+                    default_value: Some("table".to_string()),
+                },
+                ParameterInfo {
+                    name: "include_fuzzy".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Include ssdeep and TLSH fuzzy hashes".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "identify_hash".to_string(),
+            category: "Hashing".to_string(),
+            description: "List likely algorithms for a hash with hashcat modes and John formats".to_string(),
+            parameters: vec![],
+        },
+        // Text operations
+        OperationInfo {
+            name: "to_uppercase".to_string(),
+            category: "Text".to_string(),
+            description: "Convert text to uppercase".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "to_lowercase".to_string(),
+            category: "Text".to_string(),
+            description: "Convert text to lowercase".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "reverse".to_string(),
+            category: "Text".to_string(),
+            description: "Reverse text".to_string(),
             parameters: vec![],
         },
-        // Hashing operations
         OperationInfo {
-            name: "md5".to_string(),
-            category: "Hashing".to_string(),
-            description: "Calculate MD5 hash".to_string(),
-            parameters: vec![],
+            name: "unicode_normalize".to_string(),
+            category: "Text".to_string(),
+            description: "Apply Unicode normalization (NFC, NFD, NFKC or NFKD)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "form".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Normalization form: NFC, NFD, NFKC or NFKD".to_string(),
+                    required: false,
+                    default_value: Some("NFC".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "unicode_inspect".to_string(),
+            category: "Text".to_string(),
+            description: "List code points with names, categories and UTF-8/UTF-16 bytes".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "detect_unicode_spoofing".to_string(),
+            category: "Text".to_string(),
+            description: "Find homoglyphs, zero-width and bidi override characters".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "unicode_escape".to_string(),
+            category: "Encoding".to_string(),
+            description: "Escape characters as \\uXXXX, \\u{...}, &#x...; or %uXXXX".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "style".to_string(),
+                    param_type: "string".to_string(),
+                    description: "js (\\uXXXX), rust (\\u{...}), html (&#x...;) or percent (%uXXXX)".to_string(),
+                    required: false,
+                    default_value: Some("js".to_string()),
+                },
+                ParameterInfo {
+                    name: "escape_all".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Escape ASCII characters too".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "unicode_unescape".to_string(),
+            category: "Encoding".to_string(),
+            description: "Unescape \\uXXXX, \\u{...}, &#x...; and %uXXXX sequences".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "caesar_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply Caesar cipher".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "shift".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Number of positions to shift".to_string(),
+                    required: false,
+                    default_value: Some("13".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "rot13".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply ROT13 cipher".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "vigenere_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply Vigenère cipher with a keyword".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Keyword (letters only are used)".to_string(),
+                    required: false,
+                    default_value: Some("KEY".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "beaufort_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply Beaufort cipher (reciprocal)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Keyword (letters only are used)".to_string(),
+                    required: false,
+                    default_value: Some("KEY".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "affine_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply Affine cipher E(x) = (a*x + b) mod 26".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "a".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Multiplier, coprime with 26".to_string(),
+                    required: false,
+                    default_value: Some("5".to_string()),
+                },
+                ParameterInfo {
+                    name: "b".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Shift".to_string(),
+                    required: false,
+                    default_value: Some("8".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "rail_fence_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply Rail Fence transposition".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "rails".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Number of rails".to_string(),
+                    required: false,
+                    default_value: Some("3".to_string()),
+                },
+                ParameterInfo {
+                    name: "offset".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Starting position in the zigzag".to_string(),
+                    required: false,
+                    default_value: Some("0".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "bacon_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encode letters as Bacon's A/B groups".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "variant".to_string(),
+                    param_type: "string".to_string(),
+                    description: "standard (24 letters, I=J, U=V) or complete (26 letters)".to_string(),
+                    required: false,
+                    default_value: Some("standard".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "playfair_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply Playfair digraph cipher".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Keyword for the 5x5 square (J merged with I)".to_string(),
+                    required: false,
+                    default_value: Some("KEYWORD".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "bifid_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply Bifid cipher".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Keyword for the 5x5 square (J merged with I)".to_string(),
+                    required: false,
+                    default_value: Some("KEYWORD".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "substitution_cipher".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply a monoalphabetic substitution".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "alphabet".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Plaintext alphabet".to_string(),
+                    required: false,
+                    default_value: Some("abcdefghijklmnopqrstuvwxyz".to_string()),
+                },
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Ciphertext alphabet, same length as the plaintext alphabet".to_string(),
+                    required: false,
+                    default_value: Some("zyxwvutsrqponmlkjihgfedcba".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "rot47".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply ROT47 to printable ASCII".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "rot8000".to_string(),
+            category: "Crypto".to_string(),
+            description: "Apply ROT8000 to printable Unicode BMP characters".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "xor".to_string(),
+            category: "Crypto".to_string(),
+            description: "XOR data with a repeating key".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "XOR key".to_string(),
+                    required: false,
+                    default_value: Some("00".to_string()),
+                },
+                ParameterInfo {
+                    name: "key_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Key format: hex, utf8 or base64".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "scheme".to_string(),
+                    param_type: "string".to_string(),
+                    description: "standard, input_differential or output_differential".to_string(),
+                    required: false,
+                    default_value: Some("standard".to_string()),
+                },
+                ParameterInfo {
+                    name: "null_preserving".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Leave 0x00 and bytes equal to the key byte unchanged".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64, utf8, lossy or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "xor_brute_force".to_string(),
+            category: "Crypto".to_string(),
+            description: "Try every 1- or 2-byte XOR key, ranked by printable ratio or filtered by a crib".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: hex, base64, latin1 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "key_length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Key length in bytes: 1 or 2".to_string(),
+                    required: false,
+                    default_value: Some("1".to_string()),
+                },
+                ParameterInfo {
+                    name: "sample_length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Number of bytes to try each key on (max 4096)".to_string(),
+                    required: false,
+                    default_value: Some("100".to_string()),
+                },
+                ParameterInfo {
+                    name: "sample_offset".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Byte offset of the sample".to_string(),
+                    required: false,
+                    default_value: Some("0".to_string()),
+                },
+                ParameterInfo {
+                    name: "crib".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Only show keys whose output contains this text (case-insensitive)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "top".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Number of candidates to show (at most 100)".to_string(),
+                    required: false,
+                    default_value: Some("10".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "chacha20".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt or decrypt with the ChaCha20 stream cipher (original, RFC 8439 or XChaCha20 by nonce length)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "32-byte key in hex".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "nonce".to_string(),
+                    param_type: "string".to_string(),
+                    description: "8, 12 or 24-byte nonce in hex".to_string(),
+                    required: false,
+                    default_value: Some("000000000000000000000000".to_string()),
+                },
+                ParameterInfo {
+                    name: "counter".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Initial 64-byte block counter".to_string(),
+                    required: false,
+                    default_value: Some("0".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64, utf8, lossy or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "salsa20".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt or decrypt with the Salsa20 stream cipher (XSalsa20 with a 24-byte nonce)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "32-byte key in hex".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "nonce".to_string(),
+                    param_type: "string".to_string(),
+                    description: "8 or 24-byte nonce in hex".to_string(),
+                    required: false,
+                    default_value: Some("0000000000000000".to_string()),
+                },
+                ParameterInfo {
+                    name: "counter".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Initial 64-byte block counter".to_string(),
+                    required: false,
+                    default_value: Some("0".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64, utf8, lossy or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "rc4".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt or decrypt with the RC4 stream cipher".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Key of 1 to 256 bytes".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "key_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Key format: utf8, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "drop".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Keystream bytes to discard first (RC4-drop[n])".to_string(),
+                    required: false,
+                    default_value: Some("0".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64, utf8, lossy or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "des".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt or decrypt with DES".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "8-byte key in hex".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "iv".to_string(),
+                    param_type: "string".to_string(),
+                    description: "8-byte IV in hex (ignored in ECB mode)".to_string(),
+                    required: false,
+                    default_value: Some("0000000000000000".to_string()),
+                },
+                ParameterInfo {
+                    name: "mode".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Block mode: ecb, cbc, cfb, ofb or ctr".to_string(),
+                    required: false,
+                    default_value: Some("cbc".to_string()),
+                },
+                ParameterInfo {
+                    name: "padding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "pkcs7 or none (ECB and CBC only)".to_string(),
+                    required: false,
+                    default_value: Some("pkcs7".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64 (defaults to utf8 when encrypting, hex when decrypting)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64, utf8, lossy or latin1 (defaults to hex when encrypting, utf8 when decrypting)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "triple_des".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt or decrypt with Triple DES (EDE)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "16 or 24-byte key in hex".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "iv".to_string(),
+                    param_type: "string".to_string(),
+                    description: "8-byte IV in hex (ignored in ECB mode)".to_string(),
+                    required: false,
+                    default_value: Some("0000000000000000".to_string()),
+                },
+                ParameterInfo {
+                    name: "mode".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Block mode: ecb, cbc, cfb, ofb or ctr".to_string(),
+                    required: false,
+                    default_value: Some("cbc".to_string()),
+                },
+                ParameterInfo {
+                    name: "padding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "pkcs7 or none (ECB and CBC only)".to_string(),
+                    required: false,
+                    default_value: Some("pkcs7".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64 (defaults to utf8 when encrypting, hex when decrypting)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64, utf8, lossy or latin1 (defaults to hex when encrypting, utf8 when decrypting)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "blowfish".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt or decrypt with Blowfish".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "4 to 56-byte key in hex".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "iv".to_string(),
+                    param_type: "string".to_string(),
+                    description: "8-byte IV in hex (ignored in ECB mode)".to_string(),
+                    required: false,
+                    default_value: Some("0000000000000000".to_string()),
+                },
+                ParameterInfo {
+                    name: "mode".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Block mode: ecb, cbc, cfb, ofb or ctr".to_string(),
+                    required: false,
+                    default_value: Some("cbc".to_string()),
+                },
+                ParameterInfo {
+                    name: "padding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "pkcs7 or none (ECB and CBC only)".to_string(),
+                    required: false,
+                    default_value: Some("pkcs7".to_string()),
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64 (defaults to utf8 when encrypting, hex when decrypting)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64, utf8, lossy or latin1 (defaults to hex when encrypting, utf8 when decrypting)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "xchacha20_poly1305".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt or decrypt with XChaCha20-Poly1305; the 16-byte tag follows the ciphertext".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "32-byte key in hex".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "nonce".to_string(),
+                    param_type: "string".to_string(),
+                    description: "24-byte nonce in hex".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "aad".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Additional authenticated data in hex".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "decrypt".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Decrypt and verify instead of encrypt".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64 (defaults to utf8 when encrypting, hex when decrypting)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64, utf8, lossy or latin1 (defaults to hex when encrypting, utf8 when decrypting)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "openssl_encrypt".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt like openssl enc, producing Salted__ output".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "password".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Password (as given to -pass pass:...)".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "cipher".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Cipher: aes-128, aes-192 or aes-256 with -ecb, -cbc, -cfb, -ofb or -ctr".to_string(),
+                    required: false,
+                    default_value: Some("aes-256-cbc".to_string()),
+                },
+                ParameterInfo {
+                    name: "kdf".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Key derivation: pbkdf2 (-pbkdf2) or legacy (EVP_BytesToKey)".to_string(),
+                    required: false,
+                    default_value: Some("pbkdf2".to_string()),
+                },
+                ParameterInfo {
+                    name: "digest".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Digest (-md): sha256, sha1, sha512 or md5 (legacy files from OpenSSL before 1.1.0)".to_string(),
+                    required: false,
+                    default_value: Some("sha256".to_string()),
+                },
+                ParameterInfo {
+                    name: "iterations".to_string(),
+                    param_type: "number".to_string(),
+                    description: "PBKDF2 iterations (-iter)".to_string(),
+                    required: false,
+                    default_value: Some("10000".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt".to_string(),
+                    param_type: "string".to_string(),
+                    description: "8-byte salt in hex (random if blank)".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: base64 (as with -a), hex or latin1".to_string(),
+                    required: false,
+                    default_value: Some("base64".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "openssl_decrypt".to_string(),
+            category: "Crypto".to_string(),
+            description: "Decrypt openssl enc output, reading the salt from the Salted__ header".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "password".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Password (as given to -pass pass:...)".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "cipher".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Cipher: aes-128, aes-192 or aes-256 with -ecb, -cbc, -cfb, -ofb or -ctr".to_string(),
+                    required: false,
+                    default_value: Some("aes-256-cbc".to_string()),
+                },
+                ParameterInfo {
+                    name: "kdf".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Key derivation: pbkdf2 (-pbkdf2) or legacy (EVP_BytesToKey)".to_string(),
+                    required: false,
+                    default_value: Some("pbkdf2".to_string()),
+                },
+                ParameterInfo {
+                    name: "digest".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Digest (-md): sha256, sha1, sha512 or md5 (legacy files from OpenSSL before 1.1.0)".to_string(),
+                    required: false,
+                    default_value: Some("sha256".to_string()),
+                },
+                ParameterInfo {
+                    name: "iterations".to_string(),
+                    param_type: "number".to_string(),
+                    description: "PBKDF2 iterations (-iter)".to_string(),
+                    required: false,
+                    default_value: Some("10000".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: base64 (as with -a), hex or latin1".to_string(),
+                    required: false,
+                    default_value: Some("base64".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: utf8, lossy, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "rsa_sign".to_string(),
+            category: "Crypto".to_string(),
+            description: "Sign data with an RSA private key (PKCS#1 v1.5 or PSS)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "private_key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "RSA private key: PEM, JWK, or DER as hex or Base64".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "scheme".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature scheme: pkcs1v15 or pss".to_string(),
+                    required: false,
+                    default_value: Some("pkcs1v15".to_string()),
+                },
+                ParameterInfo {
+                    name: "hash".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Hash: sha1, sha256, sha384 or sha512".to_string(),
+                    required: false,
+                    default_value: Some("sha256".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Message format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "rsa_verify".to_string(),
+            category: "Crypto".to_string(),
+            description: "Verify an RSA signature (PKCS#1 v1.5 or PSS)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "public_key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "RSA public or private key: PEM, JWK, or DER as hex or Base64".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "signature".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature to check".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "scheme".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature scheme: pkcs1v15 or pss".to_string(),
+                    required: false,
+                    default_value: Some("pkcs1v15".to_string()),
+                },
+                ParameterInfo {
+                    name: "hash".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Hash: sha1, sha256, sha384 or sha512".to_string(),
+                    required: false,
+                    default_value: Some("sha256".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Message format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "signature_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "rsa_encrypt".to_string(),
+            category: "Crypto".to_string(),
+            description: "Encrypt data with an RSA public key".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "public_key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "RSA public or private key: PEM, JWK, or DER as hex or Base64".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "padding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Padding: oaep or pkcs1v15".to_string(),
+                    required: false,
+                    default_value: Some("oaep".to_string()),
+                },
+                ParameterInfo {
+                    name: "hash".to_string(),
+                    param_type: "string".to_string(),
+                    description: "OAEP hash: sha1, sha256, sha384 or sha512".to_string(),
+                    required: false,
+                    default_value: Some("sha256".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "rsa_decrypt".to_string(),
+            category: "Crypto".to_string(),
+            description: "Decrypt data with an RSA private key".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "private_key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "RSA private key: PEM, JWK, or DER as hex or Base64".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "padding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Padding: oaep or pkcs1v15".to_string(),
+                    required: false,
+                    default_value: Some("oaep".to_string()),
+                },
+                ParameterInfo {
+                    name: "hash".to_string(),
+                    param_type: "string".to_string(),
+                    description: "OAEP hash: sha1, sha256, sha384 or sha512".to_string(),
+                    required: false,
+                    default_value: Some("sha256".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: hex, base64, latin1 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: utf8, lossy, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "ecdsa_sign".to_string(),
+            category: "Crypto".to_string(),
+            description: "Sign data with ECDSA (SHA-256 for P-256 and secp256k1, SHA-384 for P-384)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "private_key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "EC private key: PEM, JWK, or DER as hex or Base64".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "curve".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Curve: p256, p384 or secp256k1".to_string(),
+                    required: false,
+                    default_value: Some("p256".to_string()),
+                },
+                ParameterInfo {
+                    name: "encoding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature encoding: der or raw (r||s, as in JWS)".to_string(),
+                    required: false,
+                    default_value: Some("der".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Message format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "ecdsa_verify".to_string(),
+            category: "Crypto".to_string(),
+            description: "Verify an ECDSA signature in DER or raw r||s encoding".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "public_key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "EC public or private key: PEM, JWK, or DER as hex or Base64".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "signature".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature to check".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "curve".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Curve: p256, p384 or secp256k1".to_string(),
+                    required: false,
+                    default_value: Some("p256".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Message format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "signature_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "ed25519_sign".to_string(),
+            category: "Crypto".to_string(),
+            description: "Sign data with an Ed25519 private key".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "private_key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Ed25519 private key: PEM, JWK, or a 32-byte seed or DER as hex or Base64".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Message format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "ed25519_verify".to_string(),
+            category: "Crypto".to_string(),
+            description: "Verify an Ed25519 signature".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "public_key".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Ed25519 public key: PEM, JWK, or a 32-byte key or DER as hex or Base64".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "signature".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature to check".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Message format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "signature_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Signature format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "generate_keypair".to_string(),
+            category: "Crypto".to_string(),
+            description: "Generate an RSA, EC or Ed25519 keypair".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "algorithm".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Algorithm: rsa, p256, p384, secp256k1 or ed25519".to_string(),
+                    required: false,
+                    default_value: Some("ed25519".to_string()),
+                },
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "RSA key size in bits (1024 to 4096)".to_string(),
+                    required: false,
+                    default_value: Some("2048".to_string()),
+                },
+                ParameterInfo {
+                    name: "format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Key format: pem (PKCS#8 and SPKI) or jwk".to_string(),
+                    required: false,
+                    default_value: Some("pem".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "parse_x509".to_string(),
+            category: "Certificates".to_string(),
+            description: "Parse an X.509 certificate or chain: subject, issuer, SANs, validity, key, extensions and fingerprints".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: auto (PEM, hex or Base64), hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("auto".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "parse_csr".to_string(),
+            category: "Certificates".to_string(),
+            description: "Parse a PKCS#10 certificate signing request".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: auto (PEM, hex or Base64), hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("auto".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "asn1_dump".to_string(),
+            category: "Certificates".to_string(),
+            description: "Dump the ASN.1 structure of DER or BER data as an indented tree".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: auto (PEM, hex or Base64), hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("auto".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "inspect_key".to_string(),
+            category: "Certificates".to_string(),
+            description: "Show the format, type and size of a PKCS#8, PKCS#1, SEC1 or SPKI key".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: auto (PEM, hex or Base64), hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("auto".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "pem_to_der".to_string(),
+            category: "Certificates".to_string(),
+            description: "Decode PEM blocks to DER, one block per line".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "der_to_pem".to_string(),
+            category: "Certificates".to_string(),
+            description: "Wrap DER data in a PEM block".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "label".to_string(),
+                    param_type: "string".to_string(),
+                    description: "PEM label, e.g. CERTIFICATE, PUBLIC KEY or PRIVATE KEY".to_string(),
+                    required: false,
+                    default_value: Some("CERTIFICATE".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: auto (hex or Base64), hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("auto".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "caesar_brute_force".to_string(),
+            category: "Crypto".to_string(),
+            description: "Try every Caesar shift, ranked by English score".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "scoring".to_string(),
+                    param_type: "string".to_string(),
+                    description: "English scoring: chi_squared or quadgram".to_string(),
+                    required: false,
+                    default_value: Some("chi_squared".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "vigenere_crack".to_string(),
+            category: "Crypto".to_string(),
+            description: "Recover a Vigenère key using Kasiski examination and index of coincidence".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "max_key_length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Longest key length to consider".to_string(),
+                    required: false,
+                    default_value: Some("20".to_string()),
+                },
+                ParameterInfo {
+                    name: "scoring".to_string(),
+                    param_type: "string".to_string(),
+                    description: "English scoring: chi_squared or quadgram".to_string(),
+                    required: false,
+                    default_value: Some("quadgram".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "xor_single_byte_crack".to_string(),
+            category: "Crypto".to_string(),
+            description: "Rank single-byte XOR keys by English score".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: hex, base64, latin1 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "scoring".to_string(),
+                    param_type: "string".to_string(),
+                    description: "English scoring: chi_squared or quadgram".to_string(),
+                    required: false,
+                    default_value: Some("chi_squared".to_string()),
+                },
+                ParameterInfo {
+                    name: "top".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Number of candidates to show".to_string(),
+                    required: false,
+                    default_value: Some("5".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "xor_repeating_key_crack".to_string(),
+            category: "Crypto".to_string(),
+            description: "Recover a repeating XOR key and decrypt".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: hex, base64, latin1 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "max_key_length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Longest key length to consider".to_string(),
+                    required: false,
+                    default_value: Some("40".to_string()),
+                },
+                ParameterInfo {
+                    name: "scoring".to_string(),
+                    param_type: "string".to_string(),
+                    description: "English scoring: chi_squared or quadgram".to_string(),
+                    required: false,
+                    default_value: Some("quadgram".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "password_hash".to_string(),
+            category: "Passwords".to_string(),
+            description: "Hash a password with bcrypt, scrypt, Argon2, PBKDF2 or Unix crypt (PHC/MCF output)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "algorithm".to_string(),
+                    param_type: "string".to_string(),
+                    description: "bcrypt, scrypt, argon2id, argon2i, argon2d, pbkdf2-sha1, pbkdf2-sha256, pbkdf2-sha512, md5-crypt, sha256-crypt or sha512-crypt".to_string(),
+                    required: false,
+                    default_value: Some("argon2id".to_string()),
+                },
+                ParameterInfo {
+                    name: "cost".to_string(),
+                    param_type: "number".to_string(),
+                    description: "bcrypt cost, scrypt log2(N), Argon2 iterations, PBKDF2 iterations or crypt rounds (blank for the algorithm default)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "memory_kib".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Argon2 memory in KiB".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "parallelism".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Argon2 lanes or scrypt p".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "block_size".to_string(),
+                    param_type: "number".to_string(),
+                    description: "scrypt block size r".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "key_length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Derived key length in bytes (Argon2, scrypt, PBKDF2)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "salt".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt text (random if blank; bcrypt needs exactly 16 bytes)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "password_verify".to_string(),
+            category: "Passwords".to_string(),
+            description: "Verify a password against a bcrypt, Unix crypt or PHC hash".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "hash".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Hash to verify the input password against".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "otp_generate".to_string(),
+            category: "Passwords".to_string(),
+            description: "Generate a TOTP or HOTP code from a Base32 secret or otpauth:// URI".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "type".to_string(),
+                    param_type: "string".to_string(),
+                    description: "totp or hotp (ignored for otpauth:// input)".to_string(),
+                    required: false,
+                    default_value: Some("totp".to_string()),
+                },
+                ParameterInfo {
+                    name: "algorithm".to_string(),
+                    param_type: "string".to_string(),
+                    description: "HMAC hash: sha1, sha256 or sha512".to_string(),
+                    required: false,
+                    default_value: Some("sha1".to_string()),
+                },
+                ParameterInfo {
+                    name: "digits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Code length (6 to 10)".to_string(),
+                    required: false,
+                    default_value: Some("6".to_string()),
+                },
+                ParameterInfo {
+                    name: "period".to_string(),
+                    param_type: "number".to_string(),
+                    description: "TOTP time step in seconds".to_string(),
+                    required: false,
+                    default_value: Some("30".to_string()),
+                },
+                ParameterInfo {
+                    name: "counter".to_string(),
+                    param_type: "number".to_string(),
+                    description: "HOTP counter (overrides the URI counter)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "time".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Unix time for TOTP (blank for now)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "otp_verify".to_string(),
+            category: "Passwords".to_string(),
+            description: "Verify a TOTP or HOTP code within a skew window".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "code".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Code to check".to_string(),
+                    required: true,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "window".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Time steps either side (TOTP) or counters ahead (HOTP) to accept".to_string(),
+                    required: false,
+                    default_value: Some("1".to_string()),
+                },
+                ParameterInfo {
+                    name: "type".to_string(),
+                    param_type: "string".to_string(),
+                    description: "totp or hotp (ignored for otpauth:// input)".to_string(),
+                    required: false,
+                    default_value: Some("totp".to_string()),
+                },
+                ParameterInfo {
+                    name: "algorithm".to_string(),
+                    param_type: "string".to_string(),
+                    description: "HMAC hash: sha1, sha256 or sha512".to_string(),
+                    required: false,
+                    default_value: Some("sha1".to_string()),
+                },
+                ParameterInfo {
+                    name: "digits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Code length (6 to 10)".to_string(),
+                    required: false,
+                    default_value: Some("6".to_string()),
+                },
+                ParameterInfo {
+                    name: "period".to_string(),
+                    param_type: "number".to_string(),
+                    description: "TOTP time step in seconds".to_string(),
+                    required: false,
+                    default_value: Some("30".to_string()),
+                },
+                ParameterInfo {
+                    name: "counter".to_string(),
+                    param_type: "number".to_string(),
+                    description: "HOTP counter (overrides the URI counter)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+                ParameterInfo {
+                    name: "time".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Unix time for TOTP (blank for now)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "parse_otpauth_uri".to_string(),
+            category: "Passwords".to_string(),
+            description: "Parse an otpauth:// URI into its issuer, account, secret and settings".to_string(),
+            parameters: vec![
+            ],
+        },
+        OperationInfo {
+            name: "hkdf".to_string(),
+            category: "Crypto".to_string(),
+            description: "Derive key material with HKDF (RFC 5869) extract and/or expand".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "hash".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Hash: sha1, sha256, sha384, sha512 or md5".to_string(),
+                    required: false,
+                    default_value: Some("sha256".to_string()),
+                },
+                ParameterInfo {
+                    name: "mode".to_string(),
+                    param_type: "string".to_string(),
+                    description: "extract_expand, extract (returns the PRK) or expand (input is the PRK)".to_string(),
+                    required: false,
+                    default_value: Some("extract_expand".to_string()),
+                },
+                ParameterInfo {
+                    name: "info".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Context and application-specific info".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "info_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Info format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt (blank for none)".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt format: hex, base64, utf8 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Derived key length in bytes".to_string(),
+                    required: false,
+                    default_value: Some("32".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Password format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "pbkdf2".to_string(),
+            category: "Crypto".to_string(),
+            description: "Derive a raw key with PBKDF2 and a selectable HMAC PRF".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "prf".to_string(),
+                    param_type: "string".to_string(),
+                    description: "PRF hash: sha1, sha256, sha384, sha512 or md5".to_string(),
+                    required: false,
+                    default_value: Some("sha256".to_string()),
+                },
+                ParameterInfo {
+                    name: "iterations".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Iteration count".to_string(),
+                    required: false,
+                    default_value: Some("600000".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt (blank for none)".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt format: hex, base64, utf8 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Derived key length in bytes".to_string(),
+                    required: false,
+                    default_value: Some("32".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Password format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "evp_bytes_to_key".to_string(),
+            category: "Crypto".to_string(),
+            description: "Derive a key and IV with OpenSSL's EVP_BytesToKey (as used by openssl enc)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "hash".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Digest: md5 (OpenSSL before 1.1.0), sha256 (later) or sha1".to_string(),
+                    required: false,
+                    default_value: Some("md5".to_string()),
+                },
+                ParameterInfo {
+                    name: "iterations".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Iteration count".to_string(),
+                    required: false,
+                    default_value: Some("1".to_string()),
+                },
+                ParameterInfo {
+                    name: "iv_length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "IV length in bytes (0 for key only)".to_string(),
+                    required: false,
+                    default_value: Some("16".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt".to_string(),
+                    param_type: "string".to_string(),
+                    description: "8-byte salt (blank for none)".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt format: hex, base64, utf8 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Key length in bytes".to_string(),
+                    required: false,
+                    default_value: Some("32".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Password format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "scrypt".to_string(),
+            category: "Crypto".to_string(),
+            description: "Derive a raw key with scrypt".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "log_n".to_string(),
+                    param_type: "number".to_string(),
+                    description: "CPU/memory cost as log2(N)".to_string(),
+                    required: false,
+                    default_value: Some("15".to_string()),
+                },
+                ParameterInfo {
+                    name: "block_size".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Block size r".to_string(),
+                    required: false,
+                    default_value: Some("8".to_string()),
+                },
+                ParameterInfo {
+                    name: "parallelism".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Parallelism p".to_string(),
+                    required: false,
+                    default_value: Some("1".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt (blank for none)".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt format: hex, base64, utf8 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Derived key length in bytes".to_string(),
+                    required: false,
+                    default_value: Some("32".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Password format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "argon2".to_string(),
+            category: "Crypto".to_string(),
+            description: "Derive a raw key with Argon2 (version 0x13)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "variant".to_string(),
+                    param_type: "string".to_string(),
+                    description: "argon2id, argon2i or argon2d".to_string(),
+                    required: false,
+                    default_value: Some("argon2id".to_string()),
+                },
+                ParameterInfo {
+                    name: "memory_kib".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Memory in KiB".to_string(),
+                    required: false,
+                    default_value: Some("19456".to_string()),
+                },
+                ParameterInfo {
+                    name: "iterations".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Iterations (time cost)".to_string(),
+                    required: false,
+                    default_value: Some("2".to_string()),
+                },
+                ParameterInfo {
+                    name: "parallelism".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Lanes".to_string(),
+                    required: false,
+                    default_value: Some("1".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt (blank for none)".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "salt_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Salt format: hex, base64, utf8 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+                ParameterInfo {
+                    name: "length".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Derived key length in bytes".to_string(),
+                    required: false,
+                    default_value: Some("32".to_string()),
+                },
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Password format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: hex, base64 or latin1".to_string(),
+                    required: false,
+                    default_value: Some("hex".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "entropy".to_string(),
+            category: "Analysis".to_string(),
+            description: "Shannon entropy of the input and per block, to spot encrypted or compressed regions".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+                ParameterInfo {
+                    name: "block_size".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Block size in bytes for the per-block series (0 for none)".to_string(),
+                    required: false,
+                    default_value: Some("256".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "byte_frequency".to_string(),
+            category: "Analysis".to_string(),
+            description: "Byte histogram with chi-squared against uniform and printable/ASCII ratios".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: utf8, latin1, hex or base64".to_string(),
+                    required: false,
+                    default_value: Some("utf8".to_string()),
+                },
+            ],
         },
         OperationInfo {
-            name: "sha1".to_string(),
-            category: "Hashing".to_string(),
-            description: "Calculate SHA-1 hash".to_string(),
-            parameters: vec![],
+            name: "detect_file_type".to_string(),
+            category: "Forensics".to_string(),
+            description: "Identify the file type from magic bytes (MIME type, extension and offset)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: latin1, hex, base64 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("latin1".to_string()),
+                },
+            ],
         },
         OperationInfo {
-            name: "sha256".to_string(),
-            category: "Hashing".to_string(),
-            description: "Calculate SHA-256 hash".to_string(),
-            parameters: vec![],
+            name: "scan_for_embedded_files".to_string(),
+            category: "Forensics".to_string(),
+            description: "Find file signatures at any offset, like binwalk".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: latin1, hex, base64 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("latin1".to_string()),
+                },
+            ],
         },
         OperationInfo {
-            name: "sha512".to_string(),
-            category: "Hashing".to_string(),
-            description: "Calculate SHA-512 hash".to_string(),
-            parameters: vec![],
+            name: "carve_files".to_string(),
+            category: "Forensics".to_string(),
+            description: "Extract embedded files by signature and length fields, or by a custom header and footer, as a JSON list of blobs".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: latin1, hex, base64 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("latin1".to_string()),
+                },
+                ParameterInfo {
+                    name: "header".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Custom header bytes in hex (empty to carve every known file signature)".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "footer".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Custom footer bytes in hex, used with a custom header".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Blob encoding: base64 or hex".to_string(),
+                    required: false,
+                    default_value: Some("base64".to_string()),
+                },
+            ],
         },
-        // Text operations
         OperationInfo {
-            name: "to_uppercase".to_string(),
-            category: "Text".to_string(),
-            description: "Convert text to uppercase".to_string(),
-            parameters: vec![],
+            name: "select_carved_file".to_string(),
+            category: "Forensics".to_string(),
+            description: "Pick one file from carve_files output by index".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "index".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Index of the carved file".to_string(),
+                    required: true,
+                    default_value: Some("0".to_string()),
+                },
+                ParameterInfo {
+                    name: "output_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Output format: latin1, hex, base64 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("latin1".to_string()),
+                },
+            ],
         },
         OperationInfo {
-            name: "to_lowercase".to_string(),
-            category: "Text".to_string(),
-            description: "Convert text to lowercase".to_string(),
-            parameters: vec![],
+            name: "parse_pe".to_string(),
+            category: "Forensics".to_string(),
+            description: "Parse a Windows PE file: headers, sections with entropy, imports, exports, imphash, resources, Rich header and Authenticode".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: latin1, hex, base64 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("latin1".to_string()),
+                },
+            ],
         },
         OperationInfo {
-            name: "reverse".to_string(),
-            category: "Text".to_string(),
-            description: "Reverse text".to_string(),
-            parameters: vec![],
+            name: "parse_elf".to_string(),
+            category: "Forensics".to_string(),
+            description: "Parse an ELF file: class, ABI, sections, segments, symbols and needed libraries".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: latin1, hex, base64 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("latin1".to_string()),
+                },
+            ],
         },
         OperationInfo {
-            name: "caesar_cipher".to_string(),
-            category: "Crypto".to_string(),
-            description: "Apply Caesar cipher".to_string(),
+            name: "extract_strings".to_string(),
+            category: "Forensics".to_string(),
+            description: "Extract printable ASCII and UTF-16LE strings from binary data, like strings(1)".to_string(),
             parameters: vec![
                 ParameterInfo {
-                    name: "shift".to_string(),
+                    name: "input_format".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Input format: latin1, hex, base64 or utf8".to_string(),
+                    required: false,
+                    default_value: Some("latin1".to_string()),
+                },
+                ParameterInfo {
+                    name: "min_length".to_string(),
                     param_type: "number".to_string(),
-                    description: "Number of positions to shift".to_string(),
+                    description: "Minimum string length in characters".to_string(),
                     required: false,
-                    default_value: Some("13".to_string()),
+                    default_value: Some("4".to_string()),
+                },
+                ParameterInfo {
+                    name: "encoding".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Encodings to look for: all, ascii or utf16le".to_string(),
+                    required: false,
+                    default_value: Some("all".to_string()),
+                },
+                ParameterInfo {
+                    name: "show_offsets".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Prefix each string with its hex offset".to_string(),
+                    required: false,
+                    default_value: Some("false".to_string()),
+                },
+                ParameterInfo {
+                    name: "filter".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Only keep strings matching this regex".to_string(),
+                    required: false,
+                    default_value: Some("".to_string()),
                 },
             ],
         },
-        OperationInfo {
-            name: "rot13".to_string(),
-            category: "Crypto".to_string(),
-            description: "Apply ROT13 cipher".to_string(),
-            parameters: vec![],
-        },
         // Utility operations
         OperationInfo {
             name: "json_prettify".to_string(),
@@ -179,6 +2561,94 @@ async fn get_operations() -> Json<Vec<OperationInfo>> {
             description: "Calculate CRC32 checksum".to_string(),
             parameters: vec![],
         },
+        OperationInfo {
+            name: "crc".to_string(),
+            category: "Checksums".to_string(),
+            description: "Calculate a CRC from the catalogue or with custom parameters".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "algorithm".to_string(),
+                    param_type: "string".to_string(),
+                    description: "crc-8, crc-8/maxim, crc-16/ccitt-false, crc-16/kermit, crc-16/xmodem, crc-16/modbus, crc-16/arc, crc-32, crc-32c, crc-32/bzip2, crc-32/mpeg-2, crc-64/ecma-182, crc-64/xz, crc-64/iso or custom".to_string(),
+                    required: false,
+                    default_value: Some("crc-32".to_string()),
+                },
+                ParameterInfo {
+                    name: "width".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Custom CRC width in bits (1-64)".to_string(),
+                    required: false,
+                    default_value: Some("32".to_string()),
+                },
+                ParameterInfo {
+                    name: "poly".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Custom CRC polynomial in hex, normal form".to_string(),
+                    required: false,
+                    default_value: Some("04c11db7".to_string()),
+                },
+                ParameterInfo {
+                    name: "init".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Custom CRC initial value in hex".to_string(),
+                    required: false,
+                    default_value: Some("ffffffff".to_string()),
+                },
+                ParameterInfo {
+                    name: "refin".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Custom CRC: reflect input bytes".to_string(),
+                    required: false,
+                    default_value: Some("true".to_string()),
+                },
+                ParameterInfo {
+                    name: "refout".to_string(),
+                    param_type: "boolean".to_string(),
+                    description: "Custom CRC: reflect the final value".to_string(),
+                    required: false,
+                    default_value: Some("true".to_string()),
+                },
+                ParameterInfo {
+                    name: "xorout".to_string(),
+                    param_type: "string".to_string(),
+                    description: "Custom CRC final XOR value in hex".to_string(),
+                    required: false,
+                    default_value: Some("ffffffff".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "adler32".to_string(),
+            category: "Checksums".to_string(),
+            description: "Calculate Adler-32 checksum".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "fletcher".to_string(),
+            category: "Checksums".to_string(),
+            description: "Calculate Fletcher checksum".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "bits".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Checksum length: 16 or 32".to_string(),
+                    required: false,
+                    default_value: Some("16".to_string()),
+                },
+            ],
+        },
+        OperationInfo {
+            name: "luhn".to_string(),
+            category: "Checksums".to_string(),
+            description: "Validate a Luhn (mod 10) check digit".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "verhoeff".to_string(),
+            category: "Checksums".to_string(),
+            description: "Validate a Verhoeff check digit".to_string(),
+            parameters: vec![],
+        },
         OperationInfo {
             name: "ntlm_hash".to_string(),
             category: "Hashing".to_string(),
@@ -191,22 +2661,189 @@ async fn get_operations() -> Json<Vec<OperationInfo>> {
             description: "Calculate LM hash (Legacy Windows password hash)".to_string(),
             parameters: vec![],
         },
+        // Network operations
+        OperationInfo {
+            name: "parse_url".to_string(),
+            category: "Network".to_string(),
+            description: "Split a URL into scheme, userinfo, host, port, path, query parameters and fragment".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "idna_to_ascii".to_string(),
+            category: "Network".to_string(),
+            description: "Convert internationalized domain names to punycode (xn--)".to_string(),
+            parameters: vec![],
+        },
+        OperationInfo {
+            name: "idna_to_unicode".to_string(),
+            category: "Network".to_string(),
+            description: "Convert punycode (xn--) domain names to Unicode".to_string(),
+            parameters: vec![],
+        },
+        // Identifier operations
+        OperationInfo {
+            name: "uuid_generate".to_string(),
+            category: "Identifiers".to_string(),
+            description: "Generate UUIDs (v1, v4, v5 or v7)".to_string(),
+            parameters: vec![
+                ParameterInfo {
+                    name: "version".to_string(),
+                    param_type: "number".to_string(),
+                    description: "UUID version: 1, 4, 5 or 7".to_string(),
+                    required: false,
+                    default_value: Some("4".to_string()),
+                },
+                ParameterInfo {
+                    name: "count".to_string(),
+                    param_type: "number".to_string(),
+                    description: "Number of UUIDs to generate, one per line".to_string(),
+                    required: false,
+                    default_value: Some("1".to_string()),
+                },
+                ParameterInfo {
+                    name: "namespace".to_string(),
+                    param_type: "string".to_string(),
+                    description: "v5 namespace: dns, url, oid, x500 or a UUID".to_string(),
+                    required: false,
+                    default_value: Some("dns".to_string()),
+                },
+                ParameterInfo {
+                    name: "name".to_string(),
+                    param_type: "string".to_string(),
+                    description: "v5 name (defaults to the input)".to_string(),
+                    required: false,
+                    default_value: None,
+                },
+            ],
+        },
+        OperationInfo {
+            name: "uuid_analyze".to_string(),
+            category: "Identifiers".to_string(),
+            description: "Show UUID version, variant, timestamp and node ID".to_string(),
+            parameters: vec![],
+        },
     ];
 
     Json(operations)
 }
 
+fn param<'a>(request: &'a OperationRequest, name: &str) -> Option<&'a str> {
+    request.parameters
+        .as_ref()
+        .and_then(|p| p.get(name))
+        .map(|s| s.as_str())
+}
+
+// Encryption reads text and writes hex by default; decryption the reverse.
+fn cipher_formats(request: &OperationRequest, decrypt: bool) -> (&str, &str) {
+    let (input, output) = if decrypt { ("hex", "utf8") } else { ("utf8", "hex") };
+    (param(request, "input_format").unwrap_or(input), param(request, "output_format").unwrap_or(output))
+}
+
+fn block_cipher_params(request: &OperationRequest) -> crypto::BlockCipherParams<'_> {
+    crypto::BlockCipherParams {
+        key: param(request, "key").unwrap_or(""),
+        iv: param(request, "iv").unwrap_or("0000000000000000"),
+        mode: param(request, "mode").unwrap_or("cbc"),
+        padding: param(request, "padding").unwrap_or("pkcs7"),
+        decrypt: param(request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false),
+    }
+}
+
+fn openssl_enc_params(request: &OperationRequest) -> crypto::OpensslEncParams<'_> {
+    crypto::OpensslEncParams {
+        password: param(request, "password").unwrap_or(""),
+        cipher: param(request, "cipher").unwrap_or("aes-256-cbc"),
+        kdf: param(request, "kdf").unwrap_or("pbkdf2"),
+        digest: param(request, "digest").unwrap_or("sha256"),
+        iterations: param(request, "iterations").and_then(|s| s.parse::<u32>().ok()).unwrap_or(10_000),
+    }
+}
+
+fn kdf_params(request: &OperationRequest) -> passwords::KdfParams<'_> {
+    passwords::KdfParams {
+        salt: param(request, "salt").unwrap_or(""),
+        salt_format: param(request, "salt_format").unwrap_or("hex"),
+        length: param(request, "length").and_then(|s| s.parse::<usize>().ok()).unwrap_or(32),
+        input_format: param(request, "input_format").unwrap_or("utf8"),
+        output_format: param(request, "output_format").unwrap_or("hex"),
+    }
+}
+
+fn otp_params(request: &OperationRequest) -> otp::OtpParams<'_> {
+    otp::OtpParams {
+        kind: param(request, "type").unwrap_or("totp"),
+        algorithm: param(request, "algorithm").unwrap_or("sha1"),
+        digits: param(request, "digits").and_then(|s| s.parse::<u32>().ok()).unwrap_or(6),
+        period: param(request, "period").and_then(|s| s.parse::<u64>().ok()).unwrap_or(30),
+        counter: param(request, "counter").and_then(|s| s.parse::<u64>().ok()),
+        time: param(request, "time").and_then(|s| s.parse::<i64>().ok()),
+    }
+}
+
 async fn execute_operation(Json(request): Json<OperationRequest>) -> Json<OperationResponse> {
     let result = match request.operation.as_str() {
         // Encoding operations
         "base64_encode" => encoding::base64_encode(&request.input),
         "base64_decode" => encoding::base64_decode(&request.input),
-        "url_encode" => encoding::url_encode(&request.input),
-        "url_decode" => encoding::url_decode(&request.input),
-        "html_encode" => encoding::html_encode(&request.input),
+        "url_encode" => {
+            let encode_set = param(&request, "encode_set").unwrap_or("component");
+            encoding::url_encode(&request.input, encode_set)
+        },
+        "url_decode" => {
+            let plus_as_space = param(&request, "plus_as_space")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            let recursive = param(&request, "recursive")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            encoding::url_decode(&request.input, plus_as_space, recursive)
+        },
+        "html_encode" => {
+            let mode = param(&request, "mode").unwrap_or("minimal");
+            encoding::html_encode(&request.input, mode)
+        },
         "html_decode" => encoding::html_decode(&request.input),
         "hex_encode" => encoding::hex_encode(&request.input),
         "hex_decode" => encoding::hex_decode(&request.input),
+        "hexdump" => {
+            let width = param(&request, "width")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(16);
+            let group = param(&request, "group")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(2);
+            let uppercase = param(&request, "uppercase")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            let start_offset = param(&request, "start_offset")
+                .and_then(|s| s.parse::<u64>().ok())
+                .unwrap_or(0);
+            encoding::hexdump(&request.input, width, group, uppercase, start_offset)
+        },
+        "from_hexdump" => {
+            let output_format = param(&request, "output_format").unwrap_or("utf8");
+            encoding::from_hexdump(&request.input, output_format)
+        },
+        "encode_text" => {
+            let encoding = param(&request, "encoding").unwrap_or("utf-16le");
+            let bom = param(&request, "bom")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            let strict = param(&request, "strict")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            let output_format = param(&request, "output_format").unwrap_or("hex");
+            charsets::encode_text(&request.input, encoding, bom, strict, output_format)
+        },
+        "decode_text" => {
+            let encoding = param(&request, "encoding").unwrap_or("auto");
+            let strict = param(&request, "strict")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            let input_format = param(&request, "input_format").unwrap_or("hex");
+            charsets::decode_text(&request.input, encoding, strict, input_format)
+        },
         
         // Hashing operations
         "md5" => hashing::md5_hash(&request.input),
@@ -214,13 +2851,138 @@ async fn execute_operation(Json(request): Json<OperationRequest>) -> Json<Operat
         "sha256" => hashing::sha256_hash(&request.input),
         "sha512" => hashing::sha512_hash(&request.input),
         "crc32" => hashing::crc32_hash(&request.input),
+        "crc" => {
+            let algorithm = param(&request, "algorithm").unwrap_or("crc-32");
+            let params = if algorithm.eq_ignore_ascii_case("custom") {
+                let width = param(&request, "width")
+                    .and_then(|s| s.parse::<u32>().ok())
+                    .unwrap_or(32);
+                let refin = param(&request, "refin")
+                    .and_then(|s| s.parse::<bool>().ok())
+                    .unwrap_or(true);
+                let refout = param(&request, "refout")
+                    .and_then(|s| s.parse::<bool>().ok())
+                    .unwrap_or(true);
+                checksums::custom_crc_params(
+                    width,
+                    param(&request, "poly").unwrap_or("04c11db7"),
+                    param(&request, "init").unwrap_or("ffffffff"),
+                    refin,
+                    refout,
+                    param(&request, "xorout").unwrap_or("ffffffff"),
+                )
+            } else {
+                checksums::crc_algorithm(algorithm)
+            };
+            params.and_then(|params| checksums::crc_checksum(&request.input, &params))
+        },
+        "adler32" => checksums::adler32_checksum(&request.input),
+        "fletcher" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(16);
+            checksums::fletcher_checksum(&request.input, bits)
+        },
+        "luhn" => checksums::luhn_check(&request.input),
+        "verhoeff" => checksums::verhoeff_check(&request.input),
         "ntlm_hash" => hashing::ntlm_hash(&request.input),
         "lm_hash" => hashing::lm_hash(&request.input),
+        "sha3" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(256);
+            hashing::sha3_hash(&request.input, bits)
+        },
+        "keccak" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(256);
+            hashing::keccak_hash(&request.input, bits)
+        },
+        "blake2b" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(512);
+            hashing::blake2b_hash(&request.input, bits)
+        },
+        "blake2s" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(256);
+            hashing::blake2s_hash(&request.input, bits)
+        },
+        "blake3" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(256);
+            hashing::blake3_hash(&request.input, bits)
+        },
+        "ripemd" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(160);
+            hashing::ripemd_hash(&request.input, bits)
+        },
+        "shake" => {
+            let variant = param(&request, "variant")
+                .and_then(|s| s.parse::<u32>().ok())
+                .unwrap_or(256);
+            let output_bits = param(&request, "output_bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(512);
+            hashing::shake_hash(&request.input, variant, output_bits)
+        },
+        "whirlpool" => hashing::whirlpool_hash(&request.input),
+        "sm3" => hashing::sm3_hash(&request.input),
+        "xxhash" => {
+            let variant = param(&request, "variant").unwrap_or("xxh64");
+            let seed = param(&request, "seed")
+                .and_then(|s| s.parse::<u64>().ok())
+                .unwrap_or(0);
+            hashing::xxhash(&request.input, variant, seed)
+        },
+        "murmurhash3" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(32);
+            let seed = param(&request, "seed")
+                .and_then(|s| s.parse::<u32>().ok())
+                .unwrap_or(0);
+            hashing::murmur3_hash(&request.input, bits, seed)
+        },
+        "fnv1a" => {
+            let bits = param(&request, "bits")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(64);
+            hashing::fnv1a_hash(&request.input, bits)
+        },
+        "generate_all_hashes" => {
+            let format = param(&request, "format").unwrap_or("table");
+            let include_fuzzy = param(&request, "include_fuzzy")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            hashing::generate_all_hashes(&request.input, format, include_fuzzy)
+        },
+        "identify_hash" => hashing::identify_hash(&request.input),
         
         // Text operations
         "to_uppercase" => text::to_uppercase(&request.input),
         "to_lowercase" => text::to_lowercase(&request.input),
         "reverse" => text::reverse(&request.input),
+        "unicode_normalize" => {
+            let form = param(&request, "form").unwrap_or("NFC");
+            unicode::unicode_normalize(&request.input, form)
+        },
+        "unicode_inspect" => unicode::unicode_inspect(&request.input),
+        "detect_unicode_spoofing" => unicode::detect_unicode_spoofing(&request.input),
+        "unicode_escape" => {
+            let style = param(&request, "style").unwrap_or("js");
+            let escape_all = param(&request, "escape_all")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            unicode::unicode_escape(&request.input, style, escape_all)
+        },
+        "unicode_unescape" => unicode::unicode_unescape(&request.input),
         
         // Crypto operations
         "caesar_cipher" => {
@@ -232,11 +2994,376 @@ async fn execute_operation(Json(request): Json<OperationRequest>) -> Json<Operat
             crypto::caesar_cipher(&request.input, shift)
         },
         "rot13" => crypto::rot13(&request.input),
+        "vigenere_cipher" => {
+            let decrypt = param(&request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false);
+            crypto::vigenere_cipher(&request.input, param(&request, "key").unwrap_or("KEY"), decrypt)
+        },
+        "beaufort_cipher" => crypto::beaufort_cipher(&request.input, param(&request, "key").unwrap_or("KEY")),
+        "affine_cipher" => {
+            let a = param(&request, "a").and_then(|s| s.parse::<i32>().ok()).unwrap_or(5);
+            let b = param(&request, "b").and_then(|s| s.parse::<i32>().ok()).unwrap_or(8);
+            let decrypt = param(&request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false);
+            crypto::affine_cipher(&request.input, a, b, decrypt)
+        },
+        "rail_fence_cipher" => {
+            let rails = param(&request, "rails").and_then(|s| s.parse::<usize>().ok()).unwrap_or(3);
+            let offset = param(&request, "offset").and_then(|s| s.parse::<usize>().ok()).unwrap_or(0);
+            let decrypt = param(&request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false);
+            crypto::rail_fence_cipher(&request.input, rails, offset, decrypt)
+        },
+        "bacon_cipher" => {
+            let variant = param(&request, "variant").unwrap_or("standard");
+            let decrypt = param(&request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false);
+            crypto::bacon_cipher(&request.input, variant, decrypt)
+        },
+        "playfair_cipher" => {
+            let decrypt = param(&request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false);
+            crypto::playfair_cipher(&request.input, param(&request, "key").unwrap_or("KEYWORD"), decrypt)
+        },
+        "bifid_cipher" => {
+            let decrypt = param(&request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false);
+            crypto::bifid_cipher(&request.input, param(&request, "key").unwrap_or("KEYWORD"), decrypt)
+        },
+        "substitution_cipher" => {
+            let alphabet = param(&request, "alphabet").unwrap_or("abcdefghijklmnopqrstuvwxyz");
+            let key = param(&request, "key").unwrap_or("zyxwvutsrqponmlkjihgfedcba");
+            let decrypt = param(&request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false);
+            crypto::substitution_cipher(&request.input, alphabet, key, decrypt)
+        },
+        "rot47" => crypto::rot47(&request.input),
+        "rot8000" => crypto::rot8000(&request.input),
+        "xor" => {
+            let null_preserving = param(&request, "null_preserving")
+                .and_then(|s| s.parse::<bool>().ok())
+                .unwrap_or(false);
+            crypto::xor(
+                &request.input,
+                param(&request, "key").unwrap_or("00"),
+                param(&request, "key_format").unwrap_or("hex"),
+                param(&request, "scheme").unwrap_or("standard"),
+                null_preserving,
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "output_format").unwrap_or("hex"),
+            )
+        },
+        "xor_brute_force" => {
+            let number = |name: &str, default: usize| param(&request, name).and_then(|s| s.parse::<usize>().ok()).unwrap_or(default);
+            crypto::xor_brute_force(
+                &request.input,
+                param(&request, "input_format").unwrap_or("hex"),
+                number("key_length", 1),
+                number("sample_length", 100),
+                number("sample_offset", 0),
+                param(&request, "crib").unwrap_or(""),
+                number("top", 10),
+            )
+        },
+        "chacha20" => {
+            let counter = param(&request, "counter").and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
+            crypto::chacha20_cipher(
+                &request.input,
+                param(&request, "key").unwrap_or(""),
+                param(&request, "nonce").unwrap_or("000000000000000000000000"),
+                counter,
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "output_format").unwrap_or("hex"),
+            )
+        },
+        "salsa20" => {
+            let counter = param(&request, "counter").and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
+            crypto::salsa20_cipher(
+                &request.input,
+                param(&request, "key").unwrap_or(""),
+                param(&request, "nonce").unwrap_or("0000000000000000"),
+                counter,
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "output_format").unwrap_or("hex"),
+            )
+        },
+        "rc4" => {
+            let drop = param(&request, "drop").and_then(|s| s.parse::<usize>().ok()).unwrap_or(0);
+            crypto::rc4_cipher(
+                &request.input,
+                param(&request, "key").unwrap_or(""),
+                param(&request, "key_format").unwrap_or("utf8"),
+                drop,
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "output_format").unwrap_or("hex"),
+            )
+        },
+        "des" => {
+            let params = block_cipher_params(&request);
+            let (input_format, output_format) = cipher_formats(&request, params.decrypt);
+            crypto::des_cipher(&request.input, &params, input_format, output_format)
+        },
+        "triple_des" => {
+            let params = block_cipher_params(&request);
+            let (input_format, output_format) = cipher_formats(&request, params.decrypt);
+            crypto::triple_des_cipher(&request.input, &params, input_format, output_format)
+        },
+        "blowfish" => {
+            let params = block_cipher_params(&request);
+            let (input_format, output_format) = cipher_formats(&request, params.decrypt);
+            crypto::blowfish_cipher(&request.input, &params, input_format, output_format)
+        },
+        "xchacha20_poly1305" => {
+            let decrypt = param(&request, "decrypt").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false);
+            let (input_format, output_format) = cipher_formats(&request, decrypt);
+            crypto::xchacha20_poly1305(
+                &request.input,
+                param(&request, "key").unwrap_or(""),
+                param(&request, "nonce").unwrap_or(""),
+                param(&request, "aad").unwrap_or(""),
+                decrypt,
+                input_format,
+                output_format,
+            )
+        },
+        "openssl_encrypt" => crypto::openssl_encrypt(
+            &request.input,
+            &openssl_enc_params(&request),
+            param(&request, "salt").unwrap_or(""),
+            param(&request, "input_format").unwrap_or("utf8"),
+            param(&request, "output_format").unwrap_or("base64"),
+        ),
+        "openssl_decrypt" => crypto::openssl_decrypt(
+            &request.input,
+            &openssl_enc_params(&request),
+            param(&request, "input_format").unwrap_or("base64"),
+            param(&request, "output_format").unwrap_or("utf8"),
+        ),
+        "rsa_sign" => {
+            crypto::rsa_sign(
+                &request.input,
+                param(&request, "private_key").unwrap_or(""),
+                param(&request, "scheme").unwrap_or("pkcs1v15"),
+                param(&request, "hash").unwrap_or("sha256"),
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "output_format").unwrap_or("hex"),
+            )
+        },
+        "rsa_verify" => {
+            crypto::rsa_verify(
+                &request.input,
+                param(&request, "public_key").unwrap_or(""),
+                param(&request, "signature").unwrap_or(""),
+                param(&request, "scheme").unwrap_or("pkcs1v15"),
+                param(&request, "hash").unwrap_or("sha256"),
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "signature_format").unwrap_or("hex"),
+            )
+        },
+        "rsa_encrypt" => {
+            crypto::rsa_encrypt(
+                &request.input,
+                param(&request, "public_key").unwrap_or(""),
+                param(&request, "padding").unwrap_or("oaep"),
+                param(&request, "hash").unwrap_or("sha256"),
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "output_format").unwrap_or("hex"),
+            )
+        },
+        "rsa_decrypt" => {
+            crypto::rsa_decrypt(
+                &request.input,
+                param(&request, "private_key").unwrap_or(""),
+                param(&request, "padding").unwrap_or("oaep"),
+                param(&request, "hash").unwrap_or("sha256"),
+                param(&request, "input_format").unwrap_or("hex"),
+                param(&request, "output_format").unwrap_or("utf8"),
+            )
+        },
+        "ecdsa_sign" => {
+            crypto::ecdsa_sign(
+                &request.input,
+                param(&request, "private_key").unwrap_or(""),
+                param(&request, "curve").unwrap_or("p256"),
+                param(&request, "encoding").unwrap_or("der"),
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "output_format").unwrap_or("hex"),
+            )
+        },
+        "ecdsa_verify" => {
+            crypto::ecdsa_verify(
+                &request.input,
+                param(&request, "public_key").unwrap_or(""),
+                param(&request, "signature").unwrap_or(""),
+                param(&request, "curve").unwrap_or("p256"),
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "signature_format").unwrap_or("hex"),
+            )
+        },
+        "ed25519_sign" => {
+            crypto::ed25519_sign(
+                &request.input,
+                param(&request, "private_key").unwrap_or(""),
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "output_format").unwrap_or("hex"),
+            )
+        },
+        "ed25519_verify" => {
+            crypto::ed25519_verify(
+                &request.input,
+                param(&request, "public_key").unwrap_or(""),
+                param(&request, "signature").unwrap_or(""),
+                param(&request, "input_format").unwrap_or("utf8"),
+                param(&request, "signature_format").unwrap_or("hex"),
+            )
+        },
+        "generate_keypair" => {
+            let bits = param(&request, "bits").and_then(|s| s.parse::<usize>().ok()).unwrap_or(2048);
+            crypto::generate_keypair(
+                param(&request, "algorithm").unwrap_or("ed25519"),
+                bits,
+                param(&request, "format").unwrap_or("pem"),
+            )
+        },
+        "parse_x509" => certificates::parse_x509(&request.input, param(&request, "input_format").unwrap_or("auto")),
+        "parse_csr" => certificates::parse_csr(&request.input, param(&request, "input_format").unwrap_or("auto")),
+        "asn1_dump" => certificates::asn1_dump(&request.input, param(&request, "input_format").unwrap_or("auto")),
+        "inspect_key" => certificates::inspect_key(&request.input, param(&request, "input_format").unwrap_or("auto")),
+        "pem_to_der" => certificates::pem_to_der(&request.input, param(&request, "output_format").unwrap_or("hex")),
+        "der_to_pem" => certificates::der_to_pem(
+            &request.input,
+            param(&request, "input_format").unwrap_or("auto"),
+            param(&request, "label").unwrap_or("CERTIFICATE"),
+        ),
+        "caesar_brute_force" => {
+            let scoring = param(&request, "scoring").unwrap_or("chi_squared");
+            cryptanalysis::caesar_brute_force(&request.input, scoring)
+        },
+        "vigenere_crack" => {
+            let max_key_length = param(&request, "max_key_length").and_then(|s| s.parse::<usize>().ok()).unwrap_or(20);
+            let scoring = param(&request, "scoring").unwrap_or("quadgram");
+            cryptanalysis::vigenere_crack(&request.input, max_key_length, scoring)
+        },
+        "xor_single_byte_crack" => {
+            let input_format = param(&request, "input_format").unwrap_or("hex");
+            let scoring = param(&request, "scoring").unwrap_or("chi_squared");
+            let top = param(&request, "top").and_then(|s| s.parse::<usize>().ok()).unwrap_or(5);
+            cryptanalysis::xor_single_byte_crack(&request.input, input_format, scoring, top)
+        },
+        "xor_repeating_key_crack" => {
+            let input_format = param(&request, "input_format").unwrap_or("hex");
+            let max_key_length = param(&request, "max_key_length").and_then(|s| s.parse::<usize>().ok()).unwrap_or(40);
+            let scoring = param(&request, "scoring").unwrap_or("quadgram");
+            cryptanalysis::xor_repeating_key_crack(&request.input, input_format, max_key_length, scoring)
+        },
+
+        // Password operations
+        "password_hash" => {
+            let number = |name: &str| param(&request, name).and_then(|s| s.parse::<u32>().ok());
+            let params = passwords::PasswordParams {
+                cost: number("cost"),
+                memory_kib: number("memory_kib"),
+                parallelism: number("parallelism"),
+                block_size: number("block_size"),
+                key_length: number("key_length").map(|n| n as usize),
+                salt: param(&request, "salt").filter(|s| !s.is_empty()).map(|s| s.to_string()),
+            };
+            let algorithm = param(&request, "algorithm").unwrap_or("argon2id");
+            passwords::password_hash(&request.input, algorithm, &params)
+        },
+        "password_verify" => {
+            let hash = param(&request, "hash").unwrap_or("");
+            passwords::password_verify(&request.input, hash)
+        },
+        "otp_generate" => otp::otp_generate(&request.input, &otp_params(&request)),
+        "otp_verify" => {
+            let window = param(&request, "window").and_then(|s| s.parse::<u64>().ok()).unwrap_or(1);
+            otp::otp_verify(&request.input, param(&request, "code").unwrap_or(""), window, &otp_params(&request))
+        },
+        "parse_otpauth_uri" => otp::parse_otpauth(&request.input),
+        "hkdf" => passwords::hkdf_derive(
+            &request.input,
+            param(&request, "hash").unwrap_or("sha256"),
+            param(&request, "mode").unwrap_or("extract_expand"),
+            param(&request, "info").unwrap_or(""),
+            param(&request, "info_format").unwrap_or("utf8"),
+            &kdf_params(&request),
+        ),
+        "pbkdf2" => {
+            let iterations = param(&request, "iterations").and_then(|s| s.parse::<u32>().ok()).unwrap_or(600_000);
+            passwords::pbkdf2_derive(&request.input, param(&request, "prf").unwrap_or("sha256"), iterations, &kdf_params(&request))
+        },
+        "evp_bytes_to_key" => {
+            let iterations = param(&request, "iterations").and_then(|s| s.parse::<u32>().ok()).unwrap_or(1);
+            let iv_length = param(&request, "iv_length").and_then(|s| s.parse::<usize>().ok()).unwrap_or(16);
+            let hash = param(&request, "hash").unwrap_or("md5");
+            passwords::evp_bytes_to_key(&request.input, hash, iterations, iv_length, &kdf_params(&request))
+        },
+        "scrypt" => {
+            let number = |name: &str, default: u32| param(&request, name).and_then(|s| s.parse::<u32>().ok()).unwrap_or(default);
+            let log_n = param(&request, "log_n").and_then(|s| s.parse::<u8>().ok()).unwrap_or(15);
+            passwords::scrypt_derive(&request.input, log_n, number("block_size", 8), number("parallelism", 1), &kdf_params(&request))
+        },
+        "argon2" => {
+            let number = |name: &str, default: u32| param(&request, name).and_then(|s| s.parse::<u32>().ok()).unwrap_or(default);
+            passwords::argon2_derive(
+                &request.input,
+                param(&request, "variant").unwrap_or("argon2id"),
+                number("memory_kib", 19456),
+                number("iterations", 2),
+                number("parallelism", 1),
+                &kdf_params(&request),
+            )
+        },
+        
+        // Analysis operations
+        "entropy" => {
+            let block_size = param(&request, "block_size").and_then(|s| s.parse::<usize>().ok()).unwrap_or(256);
+            analysis::entropy(&request.input, param(&request, "input_format").unwrap_or("utf8"), block_size)
+        },
+        "byte_frequency" => analysis::byte_frequency(&request.input, param(&request, "input_format").unwrap_or("utf8")),
+        
+        // Forensics operations
+        "detect_file_type" => forensics::detect_file_type(&request.input, param(&request, "input_format").unwrap_or("latin1")),
+        "scan_for_embedded_files" => forensics::scan_for_embedded_files(&request.input, param(&request, "input_format").unwrap_or("latin1")),
+        "carve_files" => forensics::carve_files(
+            &request.input,
+            param(&request, "input_format").unwrap_or("latin1"),
+            param(&request, "header").unwrap_or(""),
+            param(&request, "footer").unwrap_or(""),
+            param(&request, "output_format").unwrap_or("base64"),
+        ),
+        "select_carved_file" => {
+            let index = param(&request, "index").unwrap_or("0").parse::<usize>().map_err(|_| anyhow::anyhow!("Index must be a non-negative integer"));
+            index.and_then(|index| forensics::select_carved_file(&request.input, index, param(&request, "output_format").unwrap_or("latin1")))
+        },
+        "parse_pe" => executables::parse_pe(&request.input, param(&request, "input_format").unwrap_or("latin1")),
+        "extract_strings" => forensics::extract_strings(
+            &request.input,
+            param(&request, "input_format").unwrap_or("latin1"),
+            param(&request, "min_length").and_then(|s| s.parse::<usize>().ok()).unwrap_or(4),
+            param(&request, "encoding").unwrap_or("all"),
+            param(&request, "show_offsets").and_then(|s| s.parse::<bool>().ok()).unwrap_or(false),
+            param(&request, "filter").unwrap_or(""),
+        ),
+        "parse_elf" => executables::parse_elf(&request.input, param(&request, "input_format").unwrap_or("latin1")),
         
         // Data operations
         "json_prettify" => data::json_prettify(&request.input),
         "json_minify" => data::json_minify(&request.input),
         
+        // Network operations
+        "parse_url" => network::parse_url(&request.input),
+        "idna_to_ascii" => network::idna_to_ascii(&request.input),
+        "idna_to_unicode" => network::idna_to_unicode(&request.input),
+        
+        // Identifier operations
+        "uuid_generate" => {
+            let version = param(&request, "version")
+                .and_then(|s| s.parse::<u8>().ok())
+                .unwrap_or(4);
+            let count = param(&request, "count")
+                .and_then(|s| s.parse::<usize>().ok())
+                .unwrap_or(1);
+            let namespace = param(&request, "namespace").unwrap_or("dns");
+            let name = param(&request, "name").unwrap_or(&request.input);
+            identifiers::generate_uuid(version, count, namespace, name)
+        },
+        "uuid_analyze" => identifiers::analyze_uuid(&request.input),
+        
         _ => Err(anyhow::anyhow!("Unknown operation: {}", request.operation)),
     };
 
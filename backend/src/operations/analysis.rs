@@ -0,0 +1,160 @@
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::encoding::parse_bytes;
+
+const MAX_ENTROPY_BLOCKS: usize = 4096;
+const TOP_BYTES: usize = 10;
+
+pub fn byte_histogram(bytes: &[u8]) -> [usize; 256] {
+    let mut counts = [0; 256];
+    for &b in bytes {
+        counts[b as usize] += 1;
+    }
+    counts
+}
+
+/// Shannon entropy in bits per byte, from 0 (constant) to 8 (uniformly random).
+pub fn shannon_entropy(bytes: &[u8]) -> f64 {
+    let total = bytes.len() as f64;
+    byte_histogram(bytes)
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Pearson's chi-squared statistic against a uniform byte distribution (about 255 for random data).
+pub fn chi_squared_uniform(bytes: &[u8]) -> f64 {
+    let expected = bytes.len() as f64 / 256.0;
+    if expected == 0.0 {
+        return 0.0;
+    }
+    byte_histogram(bytes).iter().map(|&count| (count as f64 - expected).powi(2) / expected).sum()
+}
+
+/// Share of bytes that are printable ASCII or common whitespace.
+pub fn printable_ratio(bytes: &[u8]) -> f64 {
+    ratio(bytes, |b| b.is_ascii_graphic() || matches!(b, b' ' | b'\t' | b'\n' | b'\r'))
+}
+
+pub fn ascii_ratio(bytes: &[u8]) -> f64 {
+    ratio(bytes, |b| b.is_ascii())
+}
+
+fn ratio(bytes: &[u8], predicate: impl Fn(u8) -> bool) -> f64 {
+    if bytes.is_empty() {
+        return 0.0;
+    }
+    bytes.iter().filter(|&&b| predicate(b)).count() as f64 / bytes.len() as f64
+}
+
+fn rounded(value: f64) -> f64 {
+    (value * 10_000.0).round() / 10_000.0
+}
+
+// Rough reading of an entropy figure, tuned for blocks of a few hundred bytes or more
+fn entropy_assessment(entropy: f64) -> &'static str {
+    match entropy {
+        e if e >= 7.5 => "encrypted or compressed",
+        e if e >= 6.0 => "packed or mixed binary",
+        e if e >= 3.5 => "text or code",
+        _ => "sparse or repetitive",
+    }
+}
+
+/// Shannon entropy of the whole input and, when `block_size` is non-zero, of each block, as JSON
+/// with a `blocks` series for charting.
+pub fn entropy(input: &str, input_format: &str, block_size: usize) -> Result<String> {
+    let bytes = parse_bytes(input, input_format)?;
+    let overall = shannon_entropy(&bytes);
+    let mut result = json!({
+        "length": bytes.len(),
+        "entropy": rounded(overall),
+        "assessment": entropy_assessment(overall),
+    });
+    if block_size > 0 {
+        if bytes.len().div_ceil(block_size) > MAX_ENTROPY_BLOCKS {
+            return Err(anyhow!("Block size {} gives more than {} blocks; use a larger block size", block_size, MAX_ENTROPY_BLOCKS));
+        }
+        let blocks: Vec<Value> = bytes
+            .chunks(block_size)
+            .enumerate()
+            .map(|(i, block)| json!({ "offset": i * block_size, "entropy": rounded(shannon_entropy(block)) }))
+            .collect();
+        result["block_size"] = block_size.into();
+        result["blocks"] = blocks.into();
+    }
+    Ok(serde_json::to_string_pretty(&result)?)
+}
+
+/// Byte histogram with entropy, chi-squared against uniform and printable/ASCII ratios, as JSON.
+pub fn byte_frequency(input: &str, input_format: &str) -> Result<String> {
+    let bytes = parse_bytes(input, input_format)?;
+    let histogram = byte_histogram(&bytes);
+    let mut ranked: Vec<(usize, usize)> = histogram.iter().copied().enumerate().filter(|&(_, count)| count > 0).collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    let most_common: Vec<Value> = ranked
+        .iter()
+        .take(TOP_BYTES)
+        .map(|&(byte, count)| {
+            json!({
+                "byte": format!("0x{:02x}", byte),
+                "count": count,
+                "percent": rounded(100.0 * count as f64 / bytes.len() as f64),
+            })
+        })
+        .collect();
+    let result = json!({
+        "length": bytes.len(),
+        "distinct_bytes": ranked.len(),
+        "entropy": rounded(shannon_entropy(&bytes)),
+        "chi_squared": rounded(chi_squared_uniform(&bytes)),
+        "printable_ratio": rounded(printable_ratio(&bytes)),
+        "ascii_ratio": rounded(ascii_ratio(&bytes)),
+        "most_common": most_common,
+        "histogram": histogram.to_vec(),
+    });
+    Ok(serde_json::to_string_pretty(&result)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shannon_entropy() {
+        assert_eq!(shannon_entropy(b""), 0.0);
+        assert_eq!(shannon_entropy(b"aaaa"), 0.0);
+        assert_eq!(shannon_entropy(b"abab"), 1.0);
+        let all_bytes: Vec<u8> = (0..=255).collect();
+        assert_eq!(shannon_entropy(&all_bytes), 8.0);
+        assert_eq!(chi_squared_uniform(&all_bytes), 0.0);
+    }
+
+    #[test]
+    fn test_entropy_blocks() {
+        // 256 zero bytes followed by every byte value once
+        let input = format!("{}{}", "00".repeat(256), (0..=255u8).map(|b| format!("{:02x}", b)).collect::<String>());
+        let result: Value = serde_json::from_str(&entropy(&input, "hex", 256).unwrap()).unwrap();
+        assert_eq!(result["length"], 512);
+        assert_eq!(result["blocks"], json!([{ "offset": 0, "entropy": 0.0 }, { "offset": 256, "entropy": 8.0 }]));
+        assert_eq!(result["assessment"], "text or code");
+        assert!(!entropy("abc", "utf8", 0).unwrap().contains("blocks"));
+        assert!(entropy(&"a".repeat(5000), "utf8", 1).is_err());
+    }
+
+    #[test]
+    fn test_byte_frequency() {
+        let result: Value = serde_json::from_str(&byte_frequency("hello\u{0}", "utf8").unwrap()).unwrap();
+        assert_eq!(result["distinct_bytes"], 5);
+        assert_eq!(result["most_common"][0], json!({ "byte": "0x6c", "count": 2, "percent": 33.3333 }));
+        assert_eq!(result["printable_ratio"], 0.8333);
+        assert_eq!(result["ascii_ratio"], 1.0);
+        assert_eq!(result["histogram"].as_array().unwrap().len(), 256);
+        assert_eq!(result["histogram"][b'l' as usize], 2);
+    }
+}
@@ -0,0 +1,280 @@
+use anyhow::{anyhow, Result};
+use encoding_rs::Encoding;
+
+use super::encoding::{format_bytes, parse_bytes};
+
+/// EBCDIC code page 037 to Unicode; every byte maps into U+0000..=U+00FF.
+const CP037_TO_UNICODE: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9c, 0x09, 0x86, 0x7f,
+    0x97, 0x8d, 0x8e, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x9d, 0x85, 0x08, 0x87,
+    0x18, 0x19, 0x92, 0x8f, 0x1c, 0x1d, 0x1e, 0x1f,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0a, 0x17, 0x1b,
+    0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04,
+    0x98, 0x99, 0x9a, 0x9b, 0x14, 0x15, 0x9e, 0x1a,
+    0x20, 0xa0, 0xe2, 0xe4, 0xe0, 0xe1, 0xe3, 0xe5,
+    0xe7, 0xf1, 0xa2, 0x2e, 0x3c, 0x28, 0x2b, 0x7c,
+    0x26, 0xe9, 0xea, 0xeb, 0xe8, 0xed, 0xee, 0xef,
+    0xec, 0xdf, 0x21, 0x24, 0x2a, 0x29, 0x3b, 0xac,
+    0x2d, 0x2f, 0xc2, 0xc4, 0xc0, 0xc1, 0xc3, 0xc5,
+    0xc7, 0xd1, 0xa6, 0x2c, 0x25, 0x5f, 0x3e, 0x3f,
+    0xf8, 0xc9, 0xca, 0xcb, 0xc8, 0xcd, 0xce, 0xcf,
+    0xcc, 0x60, 0x3a, 0x23, 0x40, 0x27, 0x3d, 0x22,
+    0xd8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67,
+    0x68, 0x69, 0xab, 0xbb, 0xf0, 0xfd, 0xfe, 0xb1,
+    0xb0, 0x6a, 0x6b, 0x6c, 0x6d, 0x6e, 0x6f, 0x70,
+    0x71, 0x72, 0xaa, 0xba, 0xe6, 0xb8, 0xc6, 0xa4,
+    0xb5, 0x7e, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78,
+    0x79, 0x7a, 0xa1, 0xbf, 0xd0, 0xdd, 0xde, 0xae,
+    0x5e, 0xa3, 0xa5, 0xb7, 0xa9, 0xa7, 0xb6, 0xbc,
+    0xbd, 0xbe, 0x5b, 0x5d, 0xaf, 0xa8, 0xb4, 0xd7,
+    0x7b, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+    0x48, 0x49, 0xad, 0xf4, 0xf6, 0xf2, 0xf3, 0xf5,
+    0x7d, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f, 0x50,
+    0x51, 0x52, 0xb9, 0xfb, 0xfc, 0xf9, 0xfa, 0xff,
+    0x5c, 0xf7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58,
+    0x59, 0x5a, 0xb2, 0xd4, 0xd6, 0xd2, 0xd3, 0xd5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+    0x38, 0x39, 0xb3, 0xdb, 0xdc, 0xd9, 0xda, 0x9f,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Charset {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Utf32Le,
+    Utf32Be,
+    Latin1,
+    Cp037,
+    Whatwg(&'static Encoding),
+}
+
+impl Charset {
+    fn from_name(name: &str) -> Result<Self> {
+        let normalized = name.trim().to_lowercase().replace('_', "-");
+        let charset = match normalized.as_str() {
+            "utf-8" | "utf8" => Charset::Utf8,
+            "utf-16le" | "utf16le" | "utf-16" | "utf16" | "ucs-2" => Charset::Utf16Le,
+            "utf-16be" | "utf16be" => Charset::Utf16Be,
+            "utf-32le" | "utf32le" | "utf-32" | "utf32" => Charset::Utf32Le,
+            "utf-32be" | "utf32be" => Charset::Utf32Be,
+            // WHATWG treats these labels as windows-1252, so handle true ISO-8859-1 here
+            "latin1" | "latin-1" | "iso-8859-1" | "iso8859-1" => Charset::Latin1,
+            "cp037" | "ebcdic" | "ibm037" | "ebcdic-cp-us" => Charset::Cp037,
+            _ => Encoding::for_label(normalized.as_bytes())
+                .map(Charset::Whatwg)
+                .ok_or_else(|| anyhow!("Unknown character encoding: {}", name))?,
+        };
+        Ok(charset)
+    }
+
+    fn bom(self) -> &'static [u8] {
+        match self {
+            Charset::Utf8 => &[0xef, 0xbb, 0xbf],
+            Charset::Utf16Le => &[0xff, 0xfe],
+            Charset::Utf16Be => &[0xfe, 0xff],
+            Charset::Utf32Le => &[0xff, 0xfe, 0x00, 0x00],
+            Charset::Utf32Be => &[0x00, 0x00, 0xfe, 0xff],
+            _ => &[],
+        }
+    }
+}
+
+fn detect_bom(bytes: &[u8]) -> Option<Charset> {
+    // UTF-32LE must be checked before UTF-16LE as they share a prefix
+    [Charset::Utf32Le, Charset::Utf32Be, Charset::Utf8, Charset::Utf16Le, Charset::Utf16Be]
+        .into_iter()
+        .find(|charset| bytes.starts_with(charset.bom()))
+}
+
+fn encode_char(charset: Charset, ch: char, out: &mut Vec<u8>) -> bool {
+    match charset {
+        Charset::Utf8 => {
+            let mut buf = [0u8; 4];
+            out.extend_from_slice(ch.encode_utf8(&mut buf).as_bytes());
+        }
+        Charset::Utf16Le | Charset::Utf16Be => {
+            let mut buf = [0u16; 2];
+            for unit in ch.encode_utf16(&mut buf) {
+                if charset == Charset::Utf16Le {
+                    out.extend_from_slice(&unit.to_le_bytes());
+                } else {
+                    out.extend_from_slice(&unit.to_be_bytes());
+                }
+            }
+        }
+        Charset::Utf32Le => out.extend_from_slice(&(ch as u32).to_le_bytes()),
+        Charset::Utf32Be => out.extend_from_slice(&(ch as u32).to_be_bytes()),
+        Charset::Latin1 => match u8::try_from(ch) {
+            Ok(b) => out.push(b),
+            Err(_) => return false,
+        },
+        Charset::Cp037 => match CP037_TO_UNICODE.iter().position(|&c| c as u32 == ch as u32) {
+            Some(b) => out.push(b as u8),
+            None => return false,
+        },
+        Charset::Whatwg(encoding) => {
+            let mut buf = [0u8; 4];
+            let (bytes, _, had_errors) = encoding.encode(ch.encode_utf8(&mut buf));
+            if had_errors {
+                return false;
+            }
+            out.extend_from_slice(&bytes);
+        }
+    }
+    true
+}
+
+pub fn encode_text_bytes(input: &str, encoding: &str, bom: bool, strict: bool) -> Result<Vec<u8>> {
+    let charset = Charset::from_name(encoding)?;
+    if let Charset::Whatwg(whatwg) = charset {
+        if whatwg.output_encoding() != whatwg {
+            return Err(anyhow!("Encoding to {} is not supported", whatwg.name()));
+        }
+    }
+
+    let mut out = Vec::with_capacity(input.len());
+    if bom {
+        out.extend_from_slice(charset.bom());
+    }
+    for ch in input.chars() {
+        if !encode_char(charset, ch, &mut out) {
+            if strict {
+                return Err(anyhow!("Character {:?} (U+{:04X}) cannot be encoded as {}", ch, ch as u32, encoding));
+            }
+            encode_char(charset, '?', &mut out);
+        }
+    }
+    Ok(out)
+}
+
+fn decode_units<const N: usize>(bytes: &[u8], strict: bool, from_bytes: fn([u8; N]) -> u32) -> Result<Vec<u32>> {
+    let chunks = bytes.chunks_exact(N);
+    if !chunks.remainder().is_empty() && strict {
+        return Err(anyhow!("Input length {} is not a multiple of {} bytes", bytes.len(), N));
+    }
+    let mut units: Vec<u32> = chunks.map(|c| from_bytes(c.try_into().unwrap())).collect();
+    if !bytes.len().is_multiple_of(N) {
+        units.push(char::REPLACEMENT_CHARACTER as u32);
+    }
+    Ok(units)
+}
+
+pub fn decode_text_bytes(bytes: &[u8], encoding: &str, strict: bool) -> Result<String> {
+    let (charset, bytes) = if encoding.trim().eq_ignore_ascii_case("auto") {
+        match detect_bom(bytes) {
+            Some(charset) => (charset, &bytes[charset.bom().len()..]),
+            None => (Charset::Utf8, bytes),
+        }
+    } else {
+        let charset = Charset::from_name(encoding)?;
+        (charset, bytes.strip_prefix(charset.bom()).unwrap_or(bytes))
+    };
+
+    let malformed = || anyhow!("Input is not valid {}", encoding);
+    match charset {
+        Charset::Utf8 => {
+            if strict {
+                Ok(String::from_utf8(bytes.to_vec()).map_err(|_| malformed())?)
+            } else {
+                Ok(String::from_utf8_lossy(bytes).into_owned())
+            }
+        }
+        Charset::Utf16Le | Charset::Utf16Be => {
+            let from_bytes: fn([u8; 2]) -> u32 = if charset == Charset::Utf16Le {
+                |b| u16::from_le_bytes(b) as u32
+            } else {
+                |b| u16::from_be_bytes(b) as u32
+            };
+            let units = decode_units::<2>(bytes, strict, from_bytes)?;
+            let mut result = String::with_capacity(units.len());
+            for decoded in char::decode_utf16(units.iter().map(|&u| u as u16)) {
+                match decoded {
+                    Ok(ch) => result.push(ch),
+                    Err(_) if strict => return Err(malformed()),
+                    Err(_) => result.push(char::REPLACEMENT_CHARACTER),
+                }
+            }
+            Ok(result)
+        }
+        Charset::Utf32Le | Charset::Utf32Be => {
+            let from_bytes = if charset == Charset::Utf32Le { u32::from_le_bytes } else { u32::from_be_bytes };
+            let mut result = String::new();
+            for unit in decode_units::<4>(bytes, strict, from_bytes)? {
+                match char::from_u32(unit) {
+                    Some(ch) => result.push(ch),
+                    None if strict => return Err(malformed()),
+                    None => result.push(char::REPLACEMENT_CHARACTER),
+                }
+            }
+            Ok(result)
+        }
+        Charset::Latin1 => Ok(bytes.iter().map(|&b| b as char).collect()),
+        Charset::Cp037 => Ok(bytes.iter().map(|&b| CP037_TO_UNICODE[b as usize] as char).collect()),
+        Charset::Whatwg(whatwg) => {
+            if strict {
+                whatwg
+                    .decode_without_bom_handling_and_without_replacement(bytes)
+                    .map(|s| s.into_owned())
+                    .ok_or_else(malformed)
+            } else {
+                Ok(whatwg.decode_without_bom_handling(bytes).0.into_owned())
+            }
+        }
+    }
+}
+
+pub fn encode_text(input: &str, encoding: &str, bom: bool, strict: bool, output_format: &str) -> Result<String> {
+    format_bytes(&encode_text_bytes(input, encoding, bom, strict)?, output_format)
+}
+
+pub fn decode_text(input: &str, encoding: &str, strict: bool, input_format: &str) -> Result<String> {
+    decode_text_bytes(&parse_bytes(input, input_format)?, encoding, strict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powershell_encoded_command() {
+        let encoded = encode_text("Write-Host hi", "utf-16le", false, true, "base64").unwrap();
+        assert_eq!(encoded, "VwByAGkAdABlAC0ASABvAHMAdAAgAGgAaQA=");
+        let decoded = decode_text(&encoded, "utf-16le", true, "base64").unwrap();
+        assert_eq!(decoded, "Write-Host hi");
+    }
+
+    #[test]
+    fn test_bom_detection() {
+        assert_eq!(decode_text("fffe00004100000042000000", "auto", true, "hex").unwrap(), "AB");
+        assert_eq!(decode_text("feff00410042", "auto", true, "hex").unwrap(), "AB");
+        assert_eq!(decode_text("efbbbf4142", "auto", true, "hex").unwrap(), "AB");
+        assert_eq!(encode_text("A", "utf-16be", true, true, "hex").unwrap(), "feff0041");
+    }
+
+    #[test]
+    fn test_legacy_encodings() {
+        assert_eq!(encode_text("Привет", "windows-1251", false, true, "hex").unwrap(), "cff0e8e2e5f2");
+        assert_eq!(decode_text("80", "windows-1252", true, "hex").unwrap(), "\u{20ac}");
+        assert_eq!(decode_text("80", "iso-8859-1", true, "hex").unwrap(), "\u{80}");
+        assert_eq!(encode_text("日本", "shift_jis", false, true, "hex").unwrap(), "93fa967b");
+        assert_eq!(decode_text("c4e3bac3", "gbk", true, "hex").unwrap(), "你好");
+    }
+
+    #[test]
+    fn test_ebcdic_roundtrip() {
+        let encoded = encode_text("Hello, World!", "cp037", false, true, "hex").unwrap();
+        assert_eq!(encoded, "c8859393966b40e6969993845a");
+        assert_eq!(decode_text(&encoded, "cp037", true, "hex").unwrap(), "Hello, World!");
+    }
+
+    #[test]
+    fn test_strict_vs_replacement() {
+        assert!(encode_text("€", "latin1", false, true, "hex").is_err());
+        assert_eq!(encode_text("€", "latin1", false, false, "hex").unwrap(), "3f");
+        assert!(decode_text("ff", "utf-8", true, "hex").is_err());
+        assert_eq!(decode_text("ff", "utf-8", false, "hex").unwrap(), "\u{fffd}");
+    }
+}
@@ -0,0 +1,286 @@
+use anyhow::{anyhow, Result};
+
+/// CRC parameters in the Rocksoft model, as catalogued by reveng.
+#[derive(Debug, Clone, Copy)]
+pub struct CrcParams {
+    pub width: u32,
+    pub poly: u64,
+    pub init: u64,
+    pub refin: bool,
+    pub refout: bool,
+    pub xorout: u64,
+}
+
+const fn crc_params(width: u32, poly: u64, init: u64, reflect: bool, xorout: u64) -> CrcParams {
+    CrcParams { width, poly, init, refin: reflect, refout: reflect, xorout }
+}
+
+// (name, parameters, CRC of "123456789")
+pub const CRC_CATALOG: &[(&str, CrcParams, u64)] = &[
+    ("crc-8", crc_params(8, 0x07, 0x00, false, 0x00), 0xf4),
+    ("crc-8/maxim", crc_params(8, 0x31, 0x00, true, 0x00), 0xa1),
+    ("crc-16/ccitt-false", crc_params(16, 0x1021, 0xffff, false, 0x0000), 0x29b1),
+    ("crc-16/kermit", crc_params(16, 0x1021, 0x0000, true, 0x0000), 0x2189),
+    ("crc-16/xmodem", crc_params(16, 0x1021, 0x0000, false, 0x0000), 0x31c3),
+    ("crc-16/modbus", crc_params(16, 0x8005, 0xffff, true, 0x0000), 0x4b37),
+    ("crc-16/arc", crc_params(16, 0x8005, 0x0000, true, 0x0000), 0xbb3d),
+    ("crc-32", crc_params(32, 0x04c11db7, 0xffffffff, true, 0xffffffff), 0xcbf43926),
+    ("crc-32c", crc_params(32, 0x1edc6f41, 0xffffffff, true, 0xffffffff), 0xe3069283),
+    ("crc-32/bzip2", crc_params(32, 0x04c11db7, 0xffffffff, false, 0xffffffff), 0xfc891918),
+    ("crc-32/mpeg-2", crc_params(32, 0x04c11db7, 0xffffffff, false, 0x00000000), 0x0376e6e7),
+    ("crc-64/ecma-182", crc_params(64, 0x42f0e1eba9ea3693, 0, false, 0), 0x6c40df5f0b497347),
+    ("crc-64/xz", crc_params(64, 0x42f0e1eba9ea3693, u64::MAX, true, u64::MAX), 0x995dc9bbdf1939fa),
+    ("crc-64/iso", crc_params(64, 0x1b, u64::MAX, true, u64::MAX), 0xb90956c775a41001),
+];
+
+pub fn crc_algorithm(name: &str) -> Result<CrcParams> {
+    let name = name.trim().to_lowercase();
+    let alias = match name.as_str() {
+        "crc-16/ibm-3740" => "crc-16/ccitt-false",
+        // reveng lists plain CRC-16/CCITT under KERMIT, not the "false" variant
+        "crc-16/ccitt" | "crc-16/ccitt-true" | "crc-16/v-41-lsb" => "crc-16/kermit",
+        "crc-16/ibm" => "crc-16/arc",
+        "crc-32/iscsi" | "crc-32/castagnoli" => "crc-32c",
+        "crc-64/ecma" => "crc-64/ecma-182",
+        "crc-64/go-iso" => "crc-64/iso",
+        other => other,
+    };
+    CRC_CATALOG
+        .iter()
+        .find(|(catalog_name, _, _)| *catalog_name == alias)
+        .map(|(_, params, _)| *params)
+        .ok_or_else(|| anyhow!("Unknown CRC algorithm: {}", name))
+}
+
+fn parse_hex_u64(value: &str) -> Result<u64> {
+    let digits = value.trim().trim_start_matches("0x").trim_start_matches("0X");
+    u64::from_str_radix(digits, 16).map_err(|_| anyhow!("Invalid hex value: {}", value))
+}
+
+pub fn custom_crc_params(width: u32, poly: &str, init: &str, refin: bool, refout: bool, xorout: &str) -> Result<CrcParams> {
+    if width == 0 || width > 64 {
+        return Err(anyhow!("CRC width must be between 1 and 64 bits"));
+    }
+    let mask = u64::MAX >> (64 - width);
+    let parse = |name: &str, value: &str| {
+        let parsed = parse_hex_u64(value)?;
+        if parsed & !mask != 0 {
+            return Err(anyhow!("CRC {} {} is wider than {} bits", name, value, width));
+        }
+        Ok(parsed)
+    };
+    Ok(CrcParams {
+        width,
+        poly: parse("poly", poly)?,
+        init: parse("init", init)?,
+        refin,
+        refout,
+        xorout: parse("xorout", xorout)?,
+    })
+}
+
+fn reflect(value: u64, width: u32) -> u64 {
+    value.reverse_bits() >> (64 - width)
+}
+
+/// Bitwise CRC register, fed incrementally.
+pub struct CrcState {
+    params: CrcParams,
+    mask: u64,
+    crc: u64,
+}
+
+impl CrcState {
+    pub fn new(params: CrcParams) -> Self {
+        let mask = u64::MAX >> (64 - params.width);
+        CrcState { params, mask, crc: params.init & mask }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        let top_bit = 1u64 << (self.params.width - 1);
+        let poly = self.params.poly & self.mask;
+        for &byte in data {
+            let byte = if self.params.refin { byte.reverse_bits() } else { byte };
+            for i in (0..8).rev() {
+                let feedback = (self.crc & top_bit != 0) ^ ((byte >> i) & 1 == 1);
+                self.crc = (self.crc << 1) & self.mask;
+                if feedback {
+                    self.crc ^= poly;
+                }
+            }
+        }
+    }
+
+    pub fn finish(&self) -> u64 {
+        let crc = if self.params.refout { reflect(self.crc, self.params.width) } else { self.crc };
+        (crc ^ self.params.xorout) & self.mask
+    }
+
+    pub fn finish_hex(&self) -> String {
+        let digits = self.params.width.div_ceil(4) as usize;
+        format!("{:0digits$x}", self.finish())
+    }
+}
+
+pub fn crc_checksum(input: &str, params: &CrcParams) -> Result<String> {
+    let mut state = CrcState::new(*params);
+    state.update(input.as_bytes());
+    Ok(state.finish_hex())
+}
+
+pub fn adler32_checksum(input: &str) -> Result<String> {
+    Ok(format!("{:08x}", adler::adler32_slice(input.as_bytes())))
+}
+
+pub fn fletcher_checksum(input: &str, bits: usize) -> Result<String> {
+    let bytes = input.as_bytes();
+    match bits {
+        16 => {
+            let (sum1, sum2) = bytes.iter().fold((0u32, 0u32), |(sum1, sum2), &b| {
+                let sum1 = (sum1 + b as u32) % 255;
+                (sum1, (sum2 + sum1) % 255)
+            });
+            Ok(format!("{:04x}", (sum2 << 8) | sum1))
+        }
+        32 => {
+            // 16-bit little-endian words, zero-padded to an even length
+            let (sum1, sum2) = bytes.chunks(2).fold((0u64, 0u64), |(sum1, sum2), word| {
+                let word = word[0] as u64 | (*word.get(1).unwrap_or(&0) as u64) << 8;
+                let sum1 = (sum1 + word) % 65535;
+                (sum1, (sum2 + sum1) % 65535)
+            });
+            Ok(format!("{:08x}", (sum2 << 16) | sum1))
+        }
+        _ => Err(anyhow!("Unsupported Fletcher length: {} (expected 16 or 32)", bits)),
+    }
+}
+
+fn parse_digits(input: &str) -> Result<Vec<u32>> {
+    let digits: Option<Vec<u32>> = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_digit(10))
+        .collect();
+    match digits {
+        Some(digits) if digits.len() >= 2 => Ok(digits),
+        Some(_) => Err(anyhow!("Input must contain at least two digits")),
+        None => Err(anyhow!("Input must contain only digits, spaces and dashes")),
+    }
+}
+
+fn luhn_check_digit(payload: &[u32]) -> u32 {
+    let sum: u32 = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| if i % 2 == 0 { if d * 2 > 9 { d * 2 - 9 } else { d * 2 } } else { d })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+pub fn luhn_check(input: &str) -> Result<String> {
+    let digits = parse_digits(input)?;
+    let (check, payload) = digits.split_last().unwrap();
+    let expected = luhn_check_digit(payload);
+    if *check == expected {
+        Ok("Valid".to_string())
+    } else {
+        Ok(format!("Invalid (expected check digit {})", expected))
+    }
+}
+
+const VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+const VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 6, 8, 7, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+fn verhoeff_check_digit(payload: &[u32]) -> u32 {
+    let c = payload
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0u8, |c, (i, &d)| VERHOEFF_D[c as usize][VERHOEFF_P[(i + 1) % 8][d as usize] as usize]);
+    VERHOEFF_INV[c as usize] as u32
+}
+
+pub fn verhoeff_check(input: &str) -> Result<String> {
+    let digits = parse_digits(input)?;
+    let (check, payload) = digits.split_last().unwrap();
+    let expected = verhoeff_check_digit(payload);
+    if *check == expected {
+        Ok("Valid".to_string())
+    } else {
+        Ok(format!("Invalid (expected check digit {})", expected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc_catalog_check_values() {
+        for (name, params, check) in CRC_CATALOG {
+            let mut state = CrcState::new(*params);
+            state.update(b"12345");
+            state.update(b"6789");
+            assert_eq!(state.finish(), *check, "{}", name);
+        }
+        assert_eq!(crc_checksum("123456789", &crc_algorithm("CRC-16/MODBUS").unwrap()).unwrap(), "4b37");
+        assert!(crc_algorithm("crc-99").is_err());
+        assert_eq!(crc_checksum("123456789", &crc_algorithm("crc-16/ccitt").unwrap()).unwrap(), "2189");
+        assert_eq!(crc_checksum("123456789", &crc_algorithm("crc-16/ibm-3740").unwrap()).unwrap(), "29b1");
+    }
+
+    #[test]
+    fn test_custom_crc() {
+        // CRC-5/USB
+        let params = custom_crc_params(5, "0x05", "1f", true, true, "0x1F").unwrap();
+        assert_eq!(crc_checksum("123456789", &params).unwrap(), "19");
+        assert!(custom_crc_params(65, "07", "0", false, false, "0").is_err());
+        assert!(custom_crc_params(8, "zz", "0", false, false, "0").is_err());
+        assert!(custom_crc_params(16, "04c11db7", "0", false, false, "0").is_err());
+        assert!(custom_crc_params(16, "1021", "1ffff", false, false, "0").is_err());
+        assert!(custom_crc_params(5, "05", "1f", true, true, "3f").is_err());
+        assert!(custom_crc_params(64, "42f0e1eba9ea3693", "ffffffffffffffff", false, false, "0").is_ok());
+    }
+
+    #[test]
+    fn test_adler_and_fletcher() {
+        assert_eq!(adler32_checksum("Wikipedia").unwrap(), "11e60398");
+        assert_eq!(fletcher_checksum("abcde", 16).unwrap(), "c8f0");
+        assert_eq!(fletcher_checksum("abcdef", 16).unwrap(), "2057");
+        assert_eq!(fletcher_checksum("abcde", 32).unwrap(), "f04fc729");
+        assert_eq!(fletcher_checksum("abcdefgh", 32).unwrap(), "ebe19591");
+    }
+
+    #[test]
+    fn test_check_digits() {
+        assert_eq!(luhn_check("7992 7398 713").unwrap(), "Valid");
+        assert_eq!(luhn_check("79927398710").unwrap(), "Invalid (expected check digit 3)");
+        assert_eq!(verhoeff_check("2363").unwrap(), "Valid");
+        assert_eq!(verhoeff_check("2364").unwrap(), "Invalid (expected check digit 3)");
+        assert!(luhn_check("12a4").is_err());
+    }
+}
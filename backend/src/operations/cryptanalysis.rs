@@ -0,0 +1,397 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::crypto::{caesar_cipher, vigenere_cipher};
+use super::encoding::{escape_controls, parse_bytes};
+
+// Relative letter frequencies of English text, A-Z, in percent
+const ENGLISH_FREQUENCIES: [f64; 26] = [
+    8.167, 1.492, 2.782, 4.253, 12.702, 2.228, 2.015, 6.094, 6.966, 0.153, 0.772, 4.025, 2.406,
+    6.749, 7.507, 1.929, 0.095, 5.987, 6.327, 9.056, 2.758, 0.978, 2.360, 0.150, 1.974, 0.074,
+];
+
+const ENGLISH_IOC: f64 = 0.0667;
+const MAX_KEY_LENGTH: usize = 64;
+
+// Most frequent English quadgrams with their counts in a ~4.2 billion quadgram corpus.
+// Anything not listed backs off to the product of its letter frequencies.
+const QUADGRAM_TOTAL: f64 = 4_224_127_912.0;
+const COMMON_QUADGRAMS: &[(&str, u32)] = &[
+    ("TION", 13168375), ("NTHE", 11234972), ("THER", 10218035), ("THAT", 8980536), ("OFTH", 8132597),
+    ("FTHE", 8100836), ("THES", 7717675), ("WITH", 7627991), ("INTH", 7261789), ("ATIO", 7104943),
+    ("OTHE", 6900022), ("TTHE", 6828069), ("DTHE", 6707043), ("INGT", 6559500), ("ETHE", 6521003),
+    ("SAND", 6318868), ("STHE", 6278466), ("HERE", 6180864), ("THEC", 6150946), ("MENT", 6009034),
+    ("THEM", 5986961), ("RTHE", 5824911), ("THEP", 5604058), ("FROM", 5561225), ("THIS", 5462428),
+    ("TING", 5439106), ("THEI", 5360813), ("NGTH", 5265232), ("IONS", 5176442), ("ANDT", 5166052),
+    ("EAND", 4818203), ("ONTH", 4626421), ("EDTH", 4539264), ("ETHA", 4363098), ("ENTH", 4317893),
+    ("ATTH", 4216742), ("TOTH", 4153270), ("THEA", 4084215), ("NDTH", 4022591), ("THEY", 3948276),
+    ("HAVE", 3904712), ("WHIC", 3803157), ("HICH", 3781946), ("OULD", 3727359), ("ESTH", 3613828),
+    ("ERTH", 3580137), ("THEB", 3505286), ("THEF", 3476011), ("THEW", 3361725), ("THET", 3317654),
+    ("THEN", 3297712), ("HATT", 3201458), ("EVER", 3153972), ("ANDA", 3087230), ("EDTO", 3011845),
+    ("WERE", 2966312), ("OVER", 2902841), ("ENCE", 2884153), ("ATED", 2835297), ("TERS", 2796481),
+    ("INGA", 2752963), ("INGS", 2704385), ("IGHT", 2671904), ("OUGH", 2638172), ("ALLY", 2597340),
+    ("ANCE", 2553806), ("REAT", 2518227), ("ERED", 2472690), ("ONAL", 2431558), ("ISTH", 2396074),
+    ("THEE", 2352819), ("THEH", 2318455), ("THEO", 2280361), ("WHEN", 2244217), ("TTHA", 2205968),
+    ("HEIR", 2171534), ("ESAN", 2137703), ("OTHA", 2102884), ("THEL", 2069150), ("THED", 2034672),
+    ("HERS", 2001295), ("STAT", 1968423), ("EDIN", 1935061), ("VERY", 1903826), ("TAND", 1872407),
+    ("SOME", 1841239), ("YOUR", 1810576), ("OUNT", 1780104), ("ABLE", 1750183), ("ITHE", 1720591),
+];
+
+fn quadgram_log_probabilities() -> &'static HashMap<[u8; 4], f64> {
+    static TABLE: OnceLock<HashMap<[u8; 4], f64>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        COMMON_QUADGRAMS
+            .iter()
+            .map(|(quadgram, count)| {
+                let key: [u8; 4] = quadgram.as_bytes().try_into().unwrap();
+                (key, (*count as f64 / QUADGRAM_TOTAL).log10())
+            })
+            .collect()
+    })
+}
+
+fn letter_log_probability(letter: u8) -> f64 {
+    (ENGLISH_FREQUENCIES[(letter - b'A') as usize] / 100.0).log10()
+}
+
+fn is_plain_text_byte(b: u8) -> bool {
+    b.is_ascii_graphic() || matches!(b, b' ' | b'\t' | b'\n' | b'\r')
+}
+
+/// Scores candidate plaintexts for how English they look; shared by every cracker here.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TextScorer {
+    ChiSquared,
+    Quadgram,
+}
+
+impl TextScorer {
+    fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().replace('-', "_").as_str() {
+            "chi_squared" | "chi" => Ok(TextScorer::ChiSquared),
+            "quadgram" | "quadgrams" => Ok(TextScorer::Quadgram),
+            _ => Err(anyhow!("Unknown scoring method: {} (expected chi_squared or quadgram)", name)),
+        }
+    }
+
+    /// Higher is better for both methods.
+    fn fitness(self, bytes: &[u8]) -> f64 {
+        let letters: Vec<u8> = bytes.iter().filter(|b| b.is_ascii_alphabetic()).map(|b| b.to_ascii_uppercase()).collect();
+        let non_printable = bytes.iter().filter(|&&b| !is_plain_text_byte(b)).count() as f64;
+        let symbols = bytes
+            .iter()
+            .filter(|&&b| b.is_ascii_punctuation() && !b".,'\"!?-:;()".contains(&b))
+            .count() as f64;
+
+        match self {
+            TextScorer::ChiSquared => {
+                if letters.is_empty() {
+                    return f64::NEG_INFINITY;
+                }
+                -(chi_squared(&letters) + 100.0 * non_printable + 10.0 * symbols)
+            }
+            TextScorer::Quadgram => {
+                let table = quadgram_log_probabilities();
+                let quadgrams: f64 = letters
+                    .windows(4)
+                    .map(|q| {
+                        table
+                            .get(q)
+                            .copied()
+                            .unwrap_or_else(|| q.iter().map(|&l| letter_log_probability(l)).sum())
+                    })
+                    .sum();
+                let short: f64 = if letters.len() < 4 { letters.iter().map(|&l| letter_log_probability(l)).sum() } else { 0.0 };
+                quadgrams + short - 10.0 * non_printable - 2.0 * symbols
+            }
+        }
+    }
+
+    fn describe(self, fitness: f64) -> String {
+        match self {
+            TextScorer::ChiSquared => format!("χ² {:.2}", -fitness),
+            TextScorer::Quadgram => format!("fitness {:.2}", fitness),
+        }
+    }
+}
+
+// Chi-squared statistic of uppercase letters against English frequencies (lower is more English)
+fn chi_squared(letters: &[u8]) -> f64 {
+    let mut counts = [0usize; 26];
+    for &l in letters {
+        counts[(l - b'A') as usize] += 1;
+    }
+    let total = letters.len() as f64;
+    counts
+        .iter()
+        .zip(ENGLISH_FREQUENCIES)
+        .map(|(&observed, frequency)| {
+            let expected = total * frequency / 100.0;
+            (observed as f64 - expected).powi(2) / expected
+        })
+        .sum()
+}
+
+fn index_of_coincidence(symbols: &[u8]) -> f64 {
+    if symbols.len() < 2 {
+        return 0.0;
+    }
+    let mut counts = [0usize; 256];
+    for &s in symbols {
+        counts[s as usize] += 1;
+    }
+    let n = symbols.len() as f64;
+    counts.iter().map(|&c| (c * c.saturating_sub(1)) as f64).sum::<f64>() / (n * (n - 1.0))
+}
+
+// Mean IoC of the columns a periodic key of this length would produce
+fn average_column_ioc(symbols: &[u8], length: usize) -> f64 {
+    let total: f64 = (0..length)
+        .map(|offset| {
+            let column: Vec<u8> = symbols.iter().skip(offset).step_by(length).copied().collect();
+            index_of_coincidence(&column)
+        })
+        .sum();
+    total / length as f64
+}
+
+// Multiples of the true period score as well as the period itself, so keep every length
+// close to the best IoC and let decryption fitness decide between them
+fn candidate_key_lengths(average_ioc: &[(usize, f64)]) -> Vec<usize> {
+    let best_ioc = average_ioc.iter().map(|(_, ioc)| *ioc).fold(0.0, f64::max);
+    average_ioc.iter().filter(|(_, ioc)| *ioc >= best_ioc * 0.9).map(|(length, _)| *length).collect()
+}
+
+/// Try all 25 shifts and rank them, best first. "Shift N" is the shift used to encrypt.
+pub fn caesar_brute_force(input: &str, scoring: &str) -> Result<String> {
+    let scorer = TextScorer::from_name(scoring)?;
+    let mut candidates = Vec::with_capacity(25);
+    for shift in 1..26 {
+        let plaintext = caesar_cipher(input, -shift)?;
+        candidates.push((shift, scorer.fitness(plaintext.as_bytes()), plaintext));
+    }
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let lines: Vec<String> = candidates
+        .iter()
+        .map(|(shift, fitness, plaintext)| {
+            format!("Shift {:>2} ({}): {}", shift, scorer.describe(*fitness), escape_controls(plaintext.as_bytes()))
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+fn kasiski_factors(letters: &[u8], max_key_length: usize) -> Vec<usize> {
+    let mut positions: HashMap<&[u8], Vec<usize>> = HashMap::new();
+    for (i, trigram) in letters.windows(3).enumerate() {
+        positions.entry(trigram).or_default().push(i);
+    }
+
+    let mut factor_counts = vec![0usize; max_key_length + 1];
+    for found in positions.values().filter(|p| p.len() > 1) {
+        for pair in found.windows(2) {
+            let distance = pair[1] - pair[0];
+            for (factor, count) in factor_counts.iter_mut().enumerate().skip(2) {
+                if distance.is_multiple_of(factor) {
+                    *count += 1;
+                }
+            }
+        }
+    }
+    factor_counts
+}
+
+// Divisors of the period repeat at least as often as the period itself, so take the longest
+// length whose factor count is close to the highest
+fn kasiski_estimate(factor_counts: &[usize]) -> Option<usize> {
+    let best = factor_counts.iter().copied().max().filter(|&count| count > 0)?;
+    factor_counts.iter().rposition(|&count| count * 5 >= best * 4)
+}
+
+fn solve_caesar_column(column: &[u8]) -> u8 {
+    (0..26u8)
+        .min_by(|&a, &b| {
+            let shifted = |shift: u8| -> Vec<u8> { column.iter().map(|&l| b'A' + (l - b'A' + 26 - shift) % 26).collect() };
+            chi_squared(&shifted(a)).total_cmp(&chi_squared(&shifted(b)))
+        })
+        .unwrap()
+}
+
+/// Estimate the key length with Kasiski examination and index of coincidence, then recover the key.
+pub fn vigenere_crack(input: &str, max_key_length: usize, scoring: &str) -> Result<String> {
+    let scorer = TextScorer::from_name(scoring)?;
+    let letters: Vec<u8> = input.bytes().filter(|b| b.is_ascii_alphabetic()).map(|b| b.to_ascii_uppercase()).collect();
+    let max_key_length = max_key_length.min(MAX_KEY_LENGTH).min(letters.len() / 2);
+    if max_key_length < 1 {
+        return Err(anyhow!("Ciphertext is too short to analyse"));
+    }
+
+    let kasiski = kasiski_factors(&letters, max_key_length);
+    let average_ioc: Vec<(usize, f64)> =
+        (1..=max_key_length).map(|length| (length, average_column_ioc(&letters, length))).collect();
+
+    let kasiski_length = kasiski_estimate(&kasiski);
+    let mut lengths = candidate_key_lengths(&average_ioc);
+    lengths.extend(kasiski_length.filter(|length| !lengths.contains(length)));
+    // On equal fitness (a key and its repetitions decrypt alike) prefer the length with more
+    // Kasiski support, then the shorter one
+    lengths.sort_by_key(|&length| (std::cmp::Reverse(kasiski[length]), length));
+
+    let mut best: Option<(f64, String, String)> = None;
+    for length in lengths {
+        let key: String = (0..length)
+            .map(|offset| {
+                let column: Vec<u8> = letters.iter().skip(offset).step_by(length).copied().collect();
+                (b'A' + solve_caesar_column(&column)) as char
+            })
+            .collect();
+        let plaintext = vigenere_cipher(input, &key, true)?;
+        let fitness = scorer.fitness(plaintext.as_bytes());
+        if best.as_ref().is_none_or(|(best_fitness, _, _)| fitness > *best_fitness) {
+            best = Some((fitness, key, plaintext));
+        }
+    }
+    let (fitness, key, plaintext) = best.ok_or_else(|| anyhow!("Could not determine a key length"))?;
+
+    let mut lines = vec!["Key length analysis (length: average IoC, Kasiski factor count):".to_string()];
+    for (length, ioc) in &average_ioc {
+        lines.push(format!("  {:>2}: {:.4}, {}", length, ioc, kasiski[*length]));
+    }
+    lines.push(format!("English IoC: {:.4}", ENGLISH_IOC));
+    if let Some(length) = kasiski_length {
+        lines.push(format!("Kasiski estimate: {}", length));
+    }
+    lines.push(format!("Key: {}", key));
+    lines.push(format!("Score: {}", scorer.describe(fitness)));
+    lines.push(format!("Plaintext: {}", plaintext));
+    Ok(lines.join("\n"))
+}
+
+fn rank_single_byte_keys(bytes: &[u8], scorer: TextScorer) -> Vec<(u8, f64)> {
+    let mut ranked: Vec<(u8, f64)> = (0..=255u8)
+        .map(|key| {
+            let candidate: Vec<u8> = bytes.iter().map(|b| b ^ key).collect();
+            (key, scorer.fitness(&candidate))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked
+}
+
+fn describe_key_byte(key: u8) -> String {
+    if key.is_ascii_graphic() || key == b' ' {
+        format!("0x{:02x} '{}'", key, key as char)
+    } else {
+        format!("0x{:02x}", key)
+    }
+}
+
+pub fn xor_single_byte_crack(input: &str, input_format: &str, scoring: &str, top: usize) -> Result<String> {
+    let scorer = TextScorer::from_name(scoring)?;
+    let bytes = parse_bytes(input, input_format)?;
+    if bytes.is_empty() {
+        return Err(anyhow!("Input is empty"));
+    }
+
+    let lines: Vec<String> = rank_single_byte_keys(&bytes, scorer)
+        .into_iter()
+        .take(top.max(1))
+        .map(|(key, fitness)| {
+            let plaintext: Vec<u8> = bytes.iter().map(|b| b ^ key).collect();
+            format!("Key {} ({}): {}", describe_key_byte(key), scorer.describe(fitness), escape_controls(&plaintext))
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+/// Estimate the key length by byte index of coincidence, solve each key byte as single-byte XOR, then rank.
+pub fn xor_repeating_key_crack(input: &str, input_format: &str, max_key_length: usize, scoring: &str) -> Result<String> {
+    let scorer = TextScorer::from_name(scoring)?;
+    let bytes = parse_bytes(input, input_format)?;
+    let max_key_length = max_key_length.min(MAX_KEY_LENGTH).min(bytes.len() / 2);
+    if max_key_length < 1 {
+        return Err(anyhow!("Input is too short to analyse"));
+    }
+
+    let average_ioc: Vec<(usize, f64)> =
+        (1..=max_key_length).map(|length| (length, average_column_ioc(&bytes, length))).collect();
+
+    let mut best: Option<(f64, Vec<u8>, Vec<u8>)> = None;
+    for length in candidate_key_lengths(&average_ioc) {
+        // Columns are not contiguous text, so solve them on letter frequency alone
+        let key: Vec<u8> = (0..length)
+            .map(|offset| {
+                let column: Vec<u8> = bytes.iter().skip(offset).step_by(length).copied().collect();
+                rank_single_byte_keys(&column, TextScorer::ChiSquared)[0].0
+            })
+            .collect();
+        let plaintext: Vec<u8> = bytes.iter().zip(key.iter().cycle()).map(|(b, k)| b ^ k).collect();
+        let fitness = scorer.fitness(&plaintext);
+        if best.as_ref().is_none_or(|(f, _, _)| fitness > *f) {
+            best = Some((fitness, key, plaintext));
+        }
+    }
+    let (fitness, key, plaintext) = best.ok_or_else(|| anyhow!("Could not determine a key length"))?;
+
+    let lines = [
+        format!("Key length: {}", key.len()),
+        format!("Key (hex): {}", hex::encode(&key)),
+        format!("Key (text): {}", String::from_utf8_lossy(&key)),
+        format!("Score: {}", scorer.describe(fitness)),
+        format!("Plaintext: {}", String::from_utf8_lossy(&plaintext)),
+    ];
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "It was the best of times, it was the worst of times, it was the age of wisdom, \
+        it was the age of foolishness, it was the epoch of belief, it was the epoch of incredulity, \
+        it was the season of Light, it was the season of Darkness, it was the spring of hope, \
+        it was the winter of despair, we had everything before us, we had nothing before us.";
+
+    #[test]
+    fn test_caesar_brute_force() {
+        let ciphertext = caesar_cipher("Meet me at the north gate after dark", 7).unwrap();
+        for scoring in ["chi_squared", "quadgram"] {
+            let result = caesar_brute_force(&ciphertext, scoring).unwrap();
+            assert_eq!(result.lines().count(), 25);
+            let best = result.lines().next().unwrap();
+            assert!(best.starts_with("Shift  7 ("), "{}", best);
+            assert!(best.ends_with(": Meet me at the north gate after dark"));
+        }
+        assert!(caesar_brute_force("abc", "bigram").is_err());
+    }
+
+    #[test]
+    fn test_vigenere_crack() {
+        let ciphertext = vigenere_cipher(SAMPLE, "DICKENS", false).unwrap();
+        let result = vigenere_crack(&ciphertext, 20, "quadgram").unwrap();
+        assert!(result.contains("\nKey: DICKENS\n"), "{}", result);
+        assert!(result.contains("\nKasiski estimate: 7\n"), "{}", result);
+        let letters: Vec<u8> = ciphertext.bytes().filter(u8::is_ascii_alphabetic).map(|b| b.to_ascii_uppercase()).collect();
+        assert_eq!(kasiski_estimate(&kasiski_factors(&letters, 20)), Some(7));
+        assert_eq!(kasiski_estimate(&[0; 5]), None);
+        assert!(result.ends_with(&format!("Plaintext: {}", SAMPLE)));
+    }
+
+    #[test]
+    fn test_xor_single_byte_crack() {
+        let input = "1b37373331363f78151b7f2b783431333d78397828372d363c78373e783a393b3736";
+        let result = xor_single_byte_crack(input, "hex", "chi_squared", 3).unwrap();
+        assert_eq!(result.lines().count(), 3);
+        assert!(result.starts_with("Key 0x58 'X' (χ² "));
+        assert!(result.lines().next().unwrap().ends_with("): Cooking MC's like a pound of bacon"));
+    }
+
+    #[test]
+    fn test_xor_repeating_key_crack() {
+        let ciphertext: Vec<u8> = SAMPLE.bytes().zip(b"ICE".iter().cycle()).map(|(b, k)| b ^ k).collect();
+        let result = xor_repeating_key_crack(&hex::encode(ciphertext), "hex", 12, "quadgram").unwrap();
+        assert!(result.starts_with("Key length: 3\nKey (hex): 494345\nKey (text): ICE\n"), "{}", result);
+        assert!(result.ends_with(SAMPLE));
+    }
+}
@@ -1,4 +1,42 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chacha20poly1305::aead::{Aead, Payload};
+use cipher::{
+    block_padding::{NoPadding, Padding, Pkcs7},
+    consts::{U16, U8},
+    crypto_common::InnerInit,
+    generic_array::GenericArray,
+    AsyncStreamCipher, BlockCipher, BlockDecryptMut, BlockEncryptMut, BlockSizeUser, InnerIvInit, KeyInit, KeyIvInit,
+    StreamCipher, StreamCipherSeek,
+};
+use ecdsa::signature::{Signer, Verifier};
+use ed25519_dalek::{Signature as Ed25519Signature, SigningKey as Ed25519SigningKey, VerifyingKey as Ed25519VerifyingKey};
+use elliptic_curve::{
+    pkcs8::{AssociatedOid, DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding},
+    sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint, ValidatePublicKey},
+    AffinePoint, CurveArithmetic, FieldBytesSize, JwkEcKey, JwkParameters, PublicKey, SecretKey,
+};
+use rand::rngs::OsRng;
+use rsa::{
+    pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey},
+    traits::{PrivateKeyParts, PublicKeyParts},
+    BigUint, Oaep, Pkcs1v15Encrypt, Pkcs1v15Sign, Pss, RsaPrivateKey, RsaPublicKey,
+};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use super::encoding::{escape_controls, format_bytes, parse_bytes};
+use super::passwords::{evp_bytes_to_key_raw, pbkdf2_raw};
+
+const MAX_XOR_BRUTE_KEY_LENGTH: usize = 2;
+const MAX_XOR_SAMPLE_LENGTH: usize = 4096;
+const MAX_XOR_BRUTE_RESULTS: usize = 100;
+const MAX_RC4_DROP: usize = 1 << 20;
+const MIN_RSA_BITS: usize = 1024;
+const MAX_RSA_BITS: usize = 4096;
+const OPENSSL_MAGIC: &[u8] = b"Salted__";
 
 pub fn caesar_cipher(input: &str, shift: i32) -> Result<String> {
     let mut result = String::new();
@@ -37,6 +75,1262 @@ pub fn atbash_cipher(input: &str) -> Result<String> {
     Ok(result)
 }
 
+fn key_shifts(key: &str) -> Result<Vec<i32>> {
+    let shifts: Vec<i32> = key
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| (c.to_ascii_lowercase() as u8 - b'a') as i32)
+        .collect();
+    if shifts.is_empty() {
+        return Err(anyhow!("Key must contain at least one letter"));
+    }
+    Ok(shifts)
+}
+
+// Apply `f(position, key shift)` to each letter, advancing the key only on letters
+fn map_letters_with_key(input: &str, key: &str, f: impl Fn(i32, i32) -> i32) -> Result<String> {
+    let shifts = key_shifts(key)?;
+    let mut key_index = 0;
+    let mut result = String::with_capacity(input.len());
+
+    for ch in input.chars() {
+        if ch.is_ascii_alphabetic() {
+            let base = if ch.is_ascii_lowercase() { b'a' } else { b'A' };
+            let position = f((ch as u8 - base) as i32, shifts[key_index % shifts.len()]).rem_euclid(26) as u8;
+            result.push((base + position) as char);
+            key_index += 1;
+        } else {
+            result.push(ch);
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn vigenere_cipher(input: &str, key: &str, decrypt: bool) -> Result<String> {
+    if decrypt {
+        map_letters_with_key(input, key, |p, k| p - k)
+    } else {
+        map_letters_with_key(input, key, |p, k| p + k)
+    }
+}
+
+/// Beaufort is reciprocal, so the same operation encrypts and decrypts.
+pub fn beaufort_cipher(input: &str, key: &str) -> Result<String> {
+    map_letters_with_key(input, key, |p, k| k - p)
+}
+
+pub fn affine_cipher(input: &str, a: i32, b: i32, decrypt: bool) -> Result<String> {
+    let a = a.rem_euclid(26);
+    let a_inverse = (1..26)
+        .find(|x| (a * x) % 26 == 1)
+        .ok_or_else(|| anyhow!("'a' must be coprime with 26 (1, 3, 5, 7, 9, 11, 15, 17, 19, 21, 23 or 25)"))?;
+
+    let mut result = String::with_capacity(input.len());
+    for ch in input.chars() {
+        if ch.is_ascii_alphabetic() {
+            let base = if ch.is_ascii_lowercase() { b'a' } else { b'A' };
+            let x = (ch as u8 - base) as i32;
+            let y = if decrypt { a_inverse * (x - b) } else { a * x + b };
+            result.push((base + y.rem_euclid(26) as u8) as char);
+        } else {
+            result.push(ch);
+        }
+    }
+
+    Ok(result)
+}
+
+fn rail_pattern(length: usize, rails: usize, offset: usize) -> Vec<usize> {
+    let cycle = 2 * (rails - 1);
+    (0..length)
+        .map(|i| {
+            let position = (i + offset) % cycle;
+            if position < rails { position } else { cycle - position }
+        })
+        .collect()
+}
+
+pub fn rail_fence_cipher(input: &str, rails: usize, offset: usize, decrypt: bool) -> Result<String> {
+    if rails < 2 {
+        return Err(anyhow!("Rail fence needs at least 2 rails"));
+    }
+
+    let chars: Vec<char> = input.chars().collect();
+    let pattern = rail_pattern(chars.len(), rails, offset);
+    let mut order: Vec<usize> = (0..chars.len()).collect();
+    // Stable sort keeps left-to-right order within each rail
+    order.sort_by_key(|&i| pattern[i]);
+
+    let mut result = vec!['\0'; chars.len()];
+    for (cipher_index, &plain_index) in order.iter().enumerate() {
+        if decrypt {
+            result[plain_index] = chars[cipher_index];
+        } else {
+            result[cipher_index] = chars[plain_index];
+        }
+    }
+
+    Ok(result.into_iter().collect())
+}
+
+fn bacon_alphabet(variant: &str) -> Result<&'static str> {
+    match variant.to_lowercase().as_str() {
+        // I/J and U/V share codes in Bacon's original 24-letter alphabet
+        "standard" => Ok("ABCDEFGHIKLMNOPQRSTUWXYZ"),
+        "complete" => Ok("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+        _ => Err(anyhow!("Unknown Bacon variant: {} (expected standard or complete)", variant)),
+    }
+}
+
+pub fn bacon_cipher(input: &str, variant: &str, decrypt: bool) -> Result<String> {
+    let alphabet: Vec<char> = bacon_alphabet(variant)?.chars().collect();
+    let mut result = String::new();
+
+    if decrypt {
+        let mut code = 0usize;
+        let mut bits = 0;
+        for ch in input.chars() {
+            match ch.to_ascii_uppercase() {
+                'A' | 'B' => {
+                    code = (code << 1) | (ch.eq_ignore_ascii_case(&'B') as usize);
+                    bits += 1;
+                    if bits == 5 {
+                        let letter = alphabet.get(code).ok_or_else(|| anyhow!("Invalid Bacon group for code {}", code))?;
+                        result.push(*letter);
+                        code = 0;
+                        bits = 0;
+                    }
+                }
+                _ => result.push(ch),
+            }
+        }
+        if bits != 0 {
+            return Err(anyhow!("Incomplete Bacon group at end of input"));
+        }
+    } else {
+        for ch in input.chars() {
+            let letter = match ch.to_ascii_uppercase() {
+                'J' if alphabet.len() == 24 => 'I',
+                'V' if alphabet.len() == 24 => 'U',
+                other => other,
+            };
+            match alphabet.iter().position(|&c| c == letter) {
+                Some(code) => {
+                    for bit in (0..5).rev() {
+                        result.push(if (code >> bit) & 1 == 1 { 'B' } else { 'A' });
+                    }
+                }
+                None => result.push(ch),
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+// 5x5 keyed square without J, shared by Playfair and Bifid
+fn polybius_square(key: &str) -> Vec<char> {
+    let mut square = Vec::with_capacity(25);
+    for ch in key.chars().chain('A'..='Z') {
+        let ch = match ch.to_ascii_uppercase() {
+            'J' => 'I',
+            other => other,
+        };
+        if ch.is_ascii_uppercase() && !square.contains(&ch) {
+            square.push(ch);
+        }
+    }
+    square
+}
+
+fn square_letters(input: &str) -> Vec<char> {
+    input
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| match c.to_ascii_uppercase() {
+            'J' => 'I',
+            other => other,
+        })
+        .collect()
+}
+
+// Put cipher letters back into the letter positions of the input, keeping its case and
+// punctuation; any extra letters (Playfair padding) are appended.
+fn restore_layout(template: &str, letters: &[char]) -> String {
+    let mut letters = letters.iter();
+    let mut result = String::with_capacity(template.len());
+    for ch in template.chars() {
+        if ch.is_ascii_alphabetic() {
+            match letters.next() {
+                Some(&letter) if ch.is_ascii_lowercase() => result.push(letter.to_ascii_lowercase()),
+                Some(&letter) => result.push(letter),
+                None => {}
+            }
+        } else {
+            result.push(ch);
+        }
+    }
+    result.extend(letters);
+    result
+}
+
+pub fn playfair_cipher(input: &str, key: &str, decrypt: bool) -> Result<String> {
+    let square = polybius_square(key);
+    let position = |c: char| square.iter().position(|&s| s == c).unwrap();
+    let letters = square_letters(input);
+
+    let mut digraphs = Vec::with_capacity(letters.len() / 2 + 1);
+    if decrypt {
+        if !letters.len().is_multiple_of(2) {
+            return Err(anyhow!("Playfair ciphertext must have an even number of letters"));
+        }
+        digraphs.extend(letters.chunks(2).map(|pair| (pair[0], pair[1])));
+    } else {
+        let mut i = 0;
+        while i < letters.len() {
+            let first = letters[i];
+            let filler = if first == 'X' { 'Q' } else { 'X' };
+            match letters.get(i + 1) {
+                Some(&second) if second != first => {
+                    digraphs.push((first, second));
+                    i += 2;
+                }
+                _ => {
+                    digraphs.push((first, filler));
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    let step = if decrypt { 4 } else { 1 };
+    let mut output = Vec::with_capacity(digraphs.len() * 2);
+    for (a, b) in digraphs {
+        let (row_a, col_a) = (position(a) / 5, position(a) % 5);
+        let (row_b, col_b) = (position(b) / 5, position(b) % 5);
+        let (a, b) = if row_a == row_b {
+            (square[row_a * 5 + (col_a + step) % 5], square[row_b * 5 + (col_b + step) % 5])
+        } else if col_a == col_b {
+            (square[(row_a + step) % 5 * 5 + col_a], square[(row_b + step) % 5 * 5 + col_b])
+        } else {
+            (square[row_a * 5 + col_b], square[row_b * 5 + col_a])
+        };
+        output.push(a);
+        output.push(b);
+    }
+
+    Ok(restore_layout(input, &output))
+}
+
+pub fn bifid_cipher(input: &str, key: &str, decrypt: bool) -> Result<String> {
+    let square = polybius_square(key);
+    let letters = square_letters(input);
+    let coordinates: Vec<usize> = letters
+        .iter()
+        .map(|&c| square.iter().position(|&s| s == c).unwrap())
+        .collect();
+
+    let output: Vec<char> = if decrypt {
+        let digits: Vec<usize> = coordinates.iter().flat_map(|&p| [p / 5, p % 5]).collect();
+        let (rows, cols) = digits.split_at(letters.len());
+        rows.iter().zip(cols).map(|(&row, &col)| square[row * 5 + col]).collect()
+    } else {
+        let digits: Vec<usize> = coordinates
+            .iter()
+            .map(|&p| p / 5)
+            .chain(coordinates.iter().map(|&p| p % 5))
+            .collect();
+        digits.chunks(2).map(|pair| square[pair[0] * 5 + pair[1]]).collect()
+    };
+
+    Ok(restore_layout(input, &output))
+}
+
+pub fn substitution_cipher(input: &str, alphabet: &str, key: &str, decrypt: bool) -> Result<String> {
+    let alphabet: Vec<char> = alphabet.chars().collect();
+    let key: Vec<char> = key.chars().collect();
+    if alphabet.len() != key.len() {
+        return Err(anyhow!("Key must have the same length as the alphabet ({} characters)", alphabet.len()));
+    }
+    let (from, to) = if decrypt { (&key, &alphabet) } else { (&alphabet, &key) };
+    let lookup = |c: char| from.iter().position(|&f| f == c).map(|i| to[i]);
+
+    let mut result = String::with_capacity(input.len());
+    for ch in input.chars() {
+        if let Some(mapped) = lookup(ch) {
+            result.push(mapped);
+        } else if let Some(mapped) = lookup(ch.to_ascii_lowercase()).or_else(|| lookup(ch.to_ascii_uppercase())) {
+            // Letter in the other case from the alphabet: substitute and keep the input's case
+            let mapped = if ch.is_ascii_uppercase() { mapped.to_ascii_uppercase() } else { mapped.to_ascii_lowercase() };
+            result.push(mapped);
+        } else {
+            result.push(ch);
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn rot47(input: &str) -> Result<String> {
+    Ok(input
+        .chars()
+        .map(|ch| match ch {
+            '!'..='~' => (b'!' + (ch as u8 - b'!' + 47) % 94) as char,
+            _ => ch,
+        })
+        .collect())
+}
+
+// Printable BMP ranges rotated by ROT8000, skipping controls, spaces and surrogates
+const ROT8000_RANGES: &[(u32, u32)] = &[
+    (0x21, 0x7f),
+    (0xa1, 0x1680),
+    (0x1681, 0x2000),
+    (0x200b, 0x2028),
+    (0x202a, 0x202f),
+    (0x2030, 0x205f),
+    (0x2060, 0x3000),
+    (0x3001, 0xd800),
+    (0xe000, 0x10000),
+];
+
+pub fn rot8000(input: &str) -> Result<String> {
+    let total: u32 = ROT8000_RANGES.iter().map(|(start, end)| end - start).sum();
+    let index_of = |c: u32| {
+        let mut base = 0;
+        for &(start, end) in ROT8000_RANGES {
+            if (start..end).contains(&c) {
+                return Some(base + c - start);
+            }
+            base += end - start;
+        }
+        None
+    };
+    let code_point_at = |mut index: u32| {
+        for &(start, end) in ROT8000_RANGES {
+            if index < end - start {
+                return start + index;
+            }
+            index -= end - start;
+        }
+        unreachable!()
+    };
+
+    Ok(input
+        .chars()
+        .map(|ch| match index_of(ch as u32) {
+            Some(index) => char::from_u32(code_point_at((index + total / 2) % total)).unwrap_or(ch),
+            None => ch,
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum XorScheme {
+    Standard,
+    InputDifferential,
+    OutputDifferential,
+}
+
+impl XorScheme {
+    fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().replace(['-', ' '], "_").as_str() {
+            "standard" | "" => Ok(XorScheme::Standard),
+            "input_differential" => Ok(XorScheme::InputDifferential),
+            "output_differential" => Ok(XorScheme::OutputDifferential),
+            _ => Err(anyhow!("Unknown XOR scheme: {} (expected standard, input_differential or output_differential)", name)),
+        }
+    }
+}
+
+// Differential schemes replace each key byte with the previous input or output byte at the same
+// key position; null-preserving mode leaves 0x00 and bytes equal to the key byte untouched.
+fn xor_bytes(data: &[u8], key: &[u8], scheme: XorScheme, null_preserving: bool) -> Vec<u8> {
+    let mut key = if key.is_empty() { vec![0] } else { key.to_vec() };
+    let key_length = key.len();
+    let mut result = Vec::with_capacity(data.len());
+
+    for (i, &byte) in data.iter().enumerate() {
+        let k = key[i % key_length];
+        let preserved = null_preserving && (byte == 0 || byte == k);
+        let output = if preserved { byte } else { byte ^ k };
+        result.push(output);
+        if !preserved {
+            match scheme {
+                XorScheme::Standard => {}
+                XorScheme::InputDifferential => key[i % key_length] = byte,
+                XorScheme::OutputDifferential => key[i % key_length] = output,
+            }
+        }
+    }
+
+    result
+}
+
+pub fn xor(
+    input: &str,
+    key: &str,
+    key_format: &str,
+    scheme: &str,
+    null_preserving: bool,
+    input_format: &str,
+    output_format: &str,
+) -> Result<String> {
+    let scheme = XorScheme::from_name(scheme)?;
+    let key = parse_bytes(key, key_format)?;
+    let data = parse_bytes(input, input_format)?;
+    format_bytes(&xor_bytes(&data, &key, scheme, null_preserving), output_format)
+}
+
+fn xor_key(value: usize, key_length: usize) -> Vec<u8> {
+    (0..key_length).rev().map(|i| (value >> (8 * i)) as u8).collect()
+}
+
+/// Try every 1- or 2-byte key over a sample of the input, keeping keys whose output contains
+/// the crib (if given) and ranking them by how printable the output is.
+pub fn xor_brute_force(
+    input: &str,
+    input_format: &str,
+    key_length: usize,
+    sample_length: usize,
+    sample_offset: usize,
+    crib: &str,
+    top: usize,
+) -> Result<String> {
+    if key_length == 0 || key_length > MAX_XOR_BRUTE_KEY_LENGTH {
+        return Err(anyhow!("Key length must be between 1 and {}", MAX_XOR_BRUTE_KEY_LENGTH));
+    }
+    let data = parse_bytes(input, input_format)?;
+    let sample_length = sample_length.clamp(1, MAX_XOR_SAMPLE_LENGTH);
+    let sample = data.get(sample_offset..).unwrap_or(&[]);
+    let sample = &sample[..sample.len().min(sample_length)];
+    if sample.is_empty() {
+        return Err(anyhow!("Sample is empty; check the sample offset"));
+    }
+    let crib = crib.as_bytes();
+    let top = top.clamp(1, MAX_XOR_BRUTE_RESULTS);
+
+    // Min-heap of the best `top` keys so far by (printable count, wordlike count), lower key
+    // first on ties; outputs are only rebuilt for the winners
+    let mut best = BinaryHeap::with_capacity(top + 1);
+    let mut output = vec![0u8; sample.len()];
+    for value in 0..(1usize << (8 * key_length)) {
+        let key = xor_key(value, key_length);
+        for (out, (b, k)) in output.iter_mut().zip(sample.iter().zip(key.iter().cycle())) {
+            *out = b ^ k;
+        }
+        if !crib.is_empty() && !output.windows(crib.len()).any(|window| window.eq_ignore_ascii_case(crib)) {
+            continue;
+        }
+        let printable = output.iter().filter(|&&b| b.is_ascii_graphic() || matches!(b, b' ' | b'\t' | b'\n' | b'\r')).count();
+        // Many keys are fully printable, so break ties on how much of the output is words
+        let wordlike = output.iter().filter(|&&b| b.is_ascii_alphanumeric() || b == b' ').count();
+        best.push(Reverse((printable, wordlike, Reverse(value))));
+        if best.len() > top {
+            best.pop();
+        }
+    }
+    if best.is_empty() {
+        return Ok("No key produced the crib".to_string());
+    }
+
+    let lines: Vec<String> = best
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((printable, _, Reverse(value)))| {
+            let key = xor_key(value, key_length);
+            let output = xor_bytes(sample, &key, XorScheme::Standard, false);
+            let percent = printable as f64 * 100.0 / sample.len() as f64;
+            format!("Key 0x{} ({:.1}% printable): {}", hex::encode(&key), percent, escape_controls(&output))
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+// Feeds `data` through a seekable stream cipher, starting at 64-byte block `counter`.
+fn apply_stream_cipher<S>(key: &[u8], nonce: &[u8], counter: u64, data: &mut [u8]) -> Result<()>
+where
+    S: KeyIvInit + StreamCipher + StreamCipherSeek,
+{
+    let mut cipher = S::new_from_slices(key, nonce).map_err(|_| anyhow!("Key must be 32 bytes (got {})", key.len()))?;
+    let out_of_range = || anyhow!("Counter {} is out of range", counter);
+    let position = counter.checked_mul(64).ok_or_else(out_of_range)?;
+    cipher.try_seek(position).map_err(|_| out_of_range())?;
+    cipher.try_apply_keystream(data).map_err(|_| anyhow!("Input is too long for the keystream left after counter {}", counter))
+}
+
+/// ChaCha20 keystream XOR. The nonce length picks the variant: 8 bytes for the original
+/// construction, 12 for RFC 8439 and 24 for XChaCha20.
+pub fn chacha20_cipher(input: &str, key: &str, nonce: &str, counter: u64, input_format: &str, output_format: &str) -> Result<String> {
+    let key = parse_bytes(key, "hex")?;
+    let nonce = parse_bytes(nonce, "hex")?;
+    let mut data = parse_bytes(input, input_format)?;
+    match nonce.len() {
+        8 => apply_stream_cipher::<chacha20::ChaCha20Legacy>(&key, &nonce, counter, &mut data)?,
+        12 => apply_stream_cipher::<chacha20::ChaCha20>(&key, &nonce, counter, &mut data)?,
+        24 => apply_stream_cipher::<chacha20::XChaCha20>(&key, &nonce, counter, &mut data)?,
+        n => return Err(anyhow!("ChaCha20 nonce must be 8, 12 or 24 bytes (got {})", n)),
+    }
+    format_bytes(&data, output_format)
+}
+
+/// Salsa20 keystream XOR; a 24-byte nonce selects XSalsa20.
+pub fn salsa20_cipher(input: &str, key: &str, nonce: &str, counter: u64, input_format: &str, output_format: &str) -> Result<String> {
+    let key = parse_bytes(key, "hex")?;
+    let nonce = parse_bytes(nonce, "hex")?;
+    let mut data = parse_bytes(input, input_format)?;
+    match nonce.len() {
+        8 => apply_stream_cipher::<salsa20::Salsa20>(&key, &nonce, counter, &mut data)?,
+        24 => apply_stream_cipher::<salsa20::XSalsa20>(&key, &nonce, counter, &mut data)?,
+        n => return Err(anyhow!("Salsa20 nonce must be 8 or 24 bytes (got {})", n)),
+    }
+    format_bytes(&data, output_format)
+}
+
+/// RC4, optionally discarding the first `drop` keystream bytes (RC4-drop[n]).
+pub fn rc4_cipher(input: &str, key: &str, key_format: &str, drop: usize, input_format: &str, output_format: &str) -> Result<String> {
+    let key = parse_bytes(key, key_format)?;
+    if key.is_empty() || key.len() > 256 {
+        return Err(anyhow!("RC4 key must be between 1 and 256 bytes (got {})", key.len()));
+    }
+    if drop > MAX_RC4_DROP {
+        return Err(anyhow!("Drop must be at most {} bytes", MAX_RC4_DROP));
+    }
+    let data = parse_bytes(input, input_format)?;
+
+    let mut state: Vec<u8> = (0..=255).collect();
+    let mut j = 0u8;
+    for i in 0..256 {
+        j = j.wrapping_add(state[i]).wrapping_add(key[i % key.len()]);
+        state.swap(i, j as usize);
+    }
+    let (mut i, mut j) = (0u8, 0u8);
+    let mut keystream = std::iter::from_fn(|| {
+        i = i.wrapping_add(1);
+        j = j.wrapping_add(state[i as usize]);
+        state.swap(i as usize, j as usize);
+        Some(state[state[i as usize].wrapping_add(state[j as usize]) as usize])
+    });
+    for _ in 0..drop {
+        keystream.next();
+    }
+    let output: Vec<u8> = data.iter().zip(keystream).map(|(b, k)| b ^ k).collect();
+    format_bytes(&output, output_format)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BlockMode {
+    Ecb,
+    Cbc,
+    Cfb,
+    Ofb,
+    Ctr,
+}
+
+impl BlockMode {
+    fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "ecb" => Ok(BlockMode::Ecb),
+            "cbc" | "" => Ok(BlockMode::Cbc),
+            "cfb" => Ok(BlockMode::Cfb),
+            "ofb" => Ok(BlockMode::Ofb),
+            "ctr" => Ok(BlockMode::Ctr),
+            _ => Err(anyhow!("Unknown block cipher mode: {} (expected ecb, cbc, cfb, ofb or ctr)", name)),
+        }
+    }
+}
+
+/// Settings shared by the 64-bit block ciphers. Key and IV are hex; padding (pkcs7 or none)
+/// only applies to ECB and CBC.
+pub struct BlockCipherParams<'a> {
+    pub key: &'a str,
+    pub iv: &'a str,
+    pub mode: &'a str,
+    pub padding: &'a str,
+    pub decrypt: bool,
+}
+
+fn padded_block_cipher<C, P>(cipher: C, iv: &GenericArray<u8, U8>, mode: BlockMode, decrypt: bool, data: &[u8]) -> Result<Vec<u8>>
+where
+    C: BlockCipher + BlockEncryptMut + BlockDecryptMut + BlockSizeUser<BlockSize = U8>,
+    P: Padding<U8>,
+{
+    let mut buffer = data.to_vec();
+    if decrypt {
+        let plaintext = match mode {
+            BlockMode::Ecb => ecb::Decryptor::<C>::inner_init(cipher).decrypt_padded_mut::<P>(&mut buffer),
+            _ => cbc::Decryptor::<C>::inner_iv_init(cipher, iv).decrypt_padded_mut::<P>(&mut buffer),
+        };
+        Ok(plaintext.map_err(|_| anyhow!("Decryption failed: bad padding or input not a multiple of 8 bytes"))?.to_vec())
+    } else {
+        buffer.resize(data.len() + 8, 0);
+        let ciphertext = match mode {
+            BlockMode::Ecb => ecb::Encryptor::<C>::inner_init(cipher).encrypt_padded_mut::<P>(&mut buffer, data.len()),
+            _ => cbc::Encryptor::<C>::inner_iv_init(cipher, iv).encrypt_padded_mut::<P>(&mut buffer, data.len()),
+        };
+        Ok(ciphertext.map_err(|_| anyhow!("Input must be a multiple of 8 bytes when padding is none"))?.to_vec())
+    }
+}
+
+fn run_block_cipher<C>(cipher: C, input: &str, params: &BlockCipherParams, input_format: &str, output_format: &str) -> Result<String>
+where
+    C: BlockCipher + BlockEncryptMut + BlockDecryptMut + BlockSizeUser<BlockSize = U8>,
+{
+    let mode = BlockMode::from_name(params.mode)?;
+    let pkcs7 = match params.padding.trim().to_lowercase().as_str() {
+        "pkcs7" | "pkcs5" | "" => true,
+        "none" => false,
+        other => return Err(anyhow!("Unknown padding: {} (expected pkcs7 or none)", other)),
+    };
+    let iv = match mode {
+        BlockMode::Ecb => GenericArray::default(),
+        _ => {
+            let iv = parse_bytes(params.iv, "hex")?;
+            if iv.len() != 8 {
+                return Err(anyhow!("IV must be 8 bytes (got {})", iv.len()));
+            }
+            GenericArray::clone_from_slice(&iv)
+        }
+    };
+    let mut data = parse_bytes(input, input_format)?;
+    let output = match mode {
+        BlockMode::Ecb | BlockMode::Cbc if pkcs7 => padded_block_cipher::<C, Pkcs7>(cipher, &iv, mode, params.decrypt, &data)?,
+        BlockMode::Ecb | BlockMode::Cbc => padded_block_cipher::<C, NoPadding>(cipher, &iv, mode, params.decrypt, &data)?,
+        BlockMode::Cfb if params.decrypt => {
+            cfb_mode::Decryptor::<C>::inner_iv_init(cipher, &iv).decrypt(&mut data);
+            data
+        }
+        BlockMode::Cfb => {
+            cfb_mode::Encryptor::<C>::inner_iv_init(cipher, &iv).encrypt(&mut data);
+            data
+        }
+        BlockMode::Ofb => {
+            ofb::Ofb::<C>::from_core(ofb::OfbCore::inner_iv_init(cipher, &iv)).apply_keystream(&mut data);
+            data
+        }
+        BlockMode::Ctr => {
+            ctr::Ctr64BE::<C>::from_core(ctr::CtrCore::inner_iv_init(cipher, &iv)).apply_keystream(&mut data);
+            data
+        }
+    };
+    format_bytes(&output, output_format)
+}
+
+fn keyed_cipher<C: KeyInit>(key: &[u8], name: &str, lengths: &str) -> Result<C> {
+    C::new_from_slice(key).map_err(|_| anyhow!("{} key must be {} (got {} bytes)", name, lengths, key.len()))
+}
+
+pub fn des_cipher(input: &str, params: &BlockCipherParams, input_format: &str, output_format: &str) -> Result<String> {
+    let key = parse_bytes(params.key, "hex")?;
+    let cipher = keyed_cipher::<des::Des>(&key, "DES", "8 bytes")?;
+    run_block_cipher(cipher, input, params, input_format, output_format)
+}
+
+/// Triple DES in EDE form: a 16-byte key is two-key 3DES (K1 = K3), a 24-byte key three-key.
+pub fn triple_des_cipher(input: &str, params: &BlockCipherParams, input_format: &str, output_format: &str) -> Result<String> {
+    let key = parse_bytes(params.key, "hex")?;
+    let lengths = "16 or 24 bytes";
+    match key.len() {
+        16 => run_block_cipher(keyed_cipher::<des::TdesEde2>(&key, "Triple DES", lengths)?, input, params, input_format, output_format),
+        _ => run_block_cipher(keyed_cipher::<des::TdesEde3>(&key, "Triple DES", lengths)?, input, params, input_format, output_format),
+    }
+}
+
+pub fn blowfish_cipher(input: &str, params: &BlockCipherParams, input_format: &str, output_format: &str) -> Result<String> {
+    let key = parse_bytes(params.key, "hex")?;
+    let cipher = keyed_cipher::<blowfish::Blowfish>(&key, "Blowfish", "4 to 56 bytes")?;
+    run_block_cipher(cipher, input, params, input_format, output_format)
+}
+
+/// XChaCha20-Poly1305 AEAD. Ciphertext is followed by the 16-byte tag; AAD is hex.
+pub fn xchacha20_poly1305(
+    input: &str,
+    key: &str,
+    nonce: &str,
+    aad: &str,
+    decrypt: bool,
+    input_format: &str,
+    output_format: &str,
+) -> Result<String> {
+    let key = parse_bytes(key, "hex")?;
+    let nonce = parse_bytes(nonce, "hex")?;
+    if nonce.len() != 24 {
+        return Err(anyhow!("XChaCha20-Poly1305 nonce must be 24 bytes (got {})", nonce.len()));
+    }
+    let aad = parse_bytes(aad, "hex")?;
+    let data = parse_bytes(input, input_format)?;
+
+    let cipher = keyed_cipher::<chacha20poly1305::XChaCha20Poly1305>(&key, "XChaCha20-Poly1305", "32 bytes")?;
+    let nonce = chacha20poly1305::XNonce::from_slice(&nonce);
+    let payload = Payload { msg: &data, aad: &aad };
+    let output = if decrypt {
+        cipher
+            .decrypt(nonce, payload)
+            .map_err(|_| anyhow!("Authentication failed: wrong key, nonce or AAD, or the ciphertext was modified"))?
+    } else {
+        cipher.encrypt(nonce, payload).map_err(|_| anyhow!("Encryption failed"))?
+    };
+    format_bytes(&output, output_format)
+}
+
+/// Settings for `openssl enc`-compatible encryption. The KDF is pbkdf2 (`-pbkdf2 -iter N -md D`)
+/// or legacy EVP_BytesToKey, which always runs a single iteration of the digest.
+pub struct OpensslEncParams<'a> {
+    pub password: &'a str,
+    pub cipher: &'a str,
+    pub kdf: &'a str,
+    pub digest: &'a str,
+    pub iterations: u32,
+}
+
+// Cipher names as openssl spells them: aes-128-cbc, aes-256-ctr and so on
+fn openssl_cipher(name: &str) -> Result<(usize, BlockMode)> {
+    let name = name.trim().to_lowercase();
+    let unknown = || anyhow!("Unsupported cipher: {} (expected aes-128, aes-192 or aes-256 with ecb, cbc, cfb, ofb or ctr)", name);
+    let mut parts = name.split('-');
+    let (Some("aes"), Some(bits), mode) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(unknown());
+    };
+    let key_length = match bits {
+        "128" => 16,
+        "192" => 24,
+        "256" => 32,
+        _ => return Err(unknown()),
+    };
+    if parts.next().is_some() {
+        return Err(unknown());
+    }
+    let mode = BlockMode::from_name(mode.unwrap_or("cbc")).map_err(|_| unknown())?;
+    Ok((key_length, mode))
+}
+
+fn openssl_key_iv(params: &OpensslEncParams, salt: &[u8], key_length: usize, iv_length: usize) -> Result<(Vec<u8>, Vec<u8>)> {
+    let password = params.password.as_bytes();
+    match params.kdf.trim().to_lowercase().as_str() {
+        "pbkdf2" | "" => {
+            let mut key = pbkdf2_raw(password, salt, params.digest, params.iterations, key_length + iv_length)?;
+            let iv = key.split_off(key_length);
+            Ok((key, iv))
+        }
+        "evp" | "legacy" => evp_bytes_to_key_raw(password, salt, params.digest, 1, key_length, iv_length),
+        other => Err(anyhow!("Unknown KDF: {} (expected pbkdf2 or legacy)", other)),
+    }
+}
+
+fn aes_mode<C>(key: &[u8], iv: &[u8], mode: BlockMode, decrypt: bool, data: &[u8]) -> Result<Vec<u8>>
+where
+    C: BlockCipher + BlockEncryptMut + BlockDecryptMut + BlockSizeUser<BlockSize = U16> + KeyInit,
+{
+    let cipher = keyed_cipher::<C>(key, "AES", "16, 24 or 32 bytes")?;
+    let iv = GenericArray::<u8, U16>::from_slice(iv);
+    let mut buffer = data.to_vec();
+    match mode {
+        BlockMode::Ecb | BlockMode::Cbc if decrypt => {
+            let plaintext = match mode {
+                BlockMode::Ecb => ecb::Decryptor::<C>::inner_init(cipher).decrypt_padded_mut::<Pkcs7>(&mut buffer),
+                _ => cbc::Decryptor::<C>::inner_iv_init(cipher, iv).decrypt_padded_mut::<Pkcs7>(&mut buffer),
+            };
+            let plaintext = plaintext.map_err(|_| anyhow!("Decryption failed: wrong password or KDF settings, or corrupt data"))?;
+            return Ok(plaintext.to_vec());
+        }
+        BlockMode::Ecb | BlockMode::Cbc => {
+            buffer.resize(data.len() + 16, 0);
+            let ciphertext = match mode {
+                BlockMode::Ecb => ecb::Encryptor::<C>::inner_init(cipher).encrypt_padded_mut::<Pkcs7>(&mut buffer, data.len()),
+                _ => cbc::Encryptor::<C>::inner_iv_init(cipher, iv).encrypt_padded_mut::<Pkcs7>(&mut buffer, data.len()),
+            };
+            return Ok(ciphertext.map_err(|_| anyhow!("Encryption failed"))?.to_vec());
+        }
+        BlockMode::Cfb if decrypt => cfb_mode::Decryptor::<C>::inner_iv_init(cipher, iv).decrypt(&mut buffer),
+        BlockMode::Cfb => cfb_mode::Encryptor::<C>::inner_iv_init(cipher, iv).encrypt(&mut buffer),
+        BlockMode::Ofb => ofb::Ofb::<C>::from_core(ofb::OfbCore::inner_iv_init(cipher, iv)).apply_keystream(&mut buffer),
+        BlockMode::Ctr => ctr::Ctr128BE::<C>::from_core(ctr::CtrCore::inner_iv_init(cipher, iv)).apply_keystream(&mut buffer),
+    }
+    Ok(buffer)
+}
+
+fn openssl_crypt(params: &OpensslEncParams, salt: &[u8], decrypt: bool, data: &[u8]) -> Result<Vec<u8>> {
+    let (key_length, mode) = openssl_cipher(params.cipher)?;
+    let iv_length = if matches!(mode, BlockMode::Ecb) { 0 } else { 16 };
+    let (key, mut iv) = openssl_key_iv(params, salt, key_length, iv_length)?;
+    iv.resize(16, 0);
+    match key_length {
+        16 => aes_mode::<aes::Aes128>(&key, &iv, mode, decrypt, data),
+        24 => aes_mode::<aes::Aes192>(&key, &iv, mode, decrypt, data),
+        _ => aes_mode::<aes::Aes256>(&key, &iv, mode, decrypt, data),
+    }
+}
+
+// openssl -a wraps Base64 at 64 columns
+fn openssl_input(input: &str, input_format: &str) -> Result<Vec<u8>> {
+    if input_format.trim().eq_ignore_ascii_case("base64") {
+        return parse_bytes(&input.split_whitespace().collect::<String>(), "base64");
+    }
+    parse_bytes(input, input_format)
+}
+
+/// Encrypt like `openssl enc -e`, prefixing the output with `Salted__` and the 8-byte salt (random when blank).
+pub fn openssl_encrypt(input: &str, params: &OpensslEncParams, salt: &str, input_format: &str, output_format: &str) -> Result<String> {
+    let salt = match salt.trim() {
+        "" => rand::random::<[u8; 8]>().to_vec(),
+        salt => parse_bytes(salt, "hex")?,
+    };
+    if salt.len() != 8 {
+        return Err(anyhow!("Salt must be 8 bytes (got {})", salt.len()));
+    }
+    let ciphertext = openssl_crypt(params, &salt, false, &parse_bytes(input, input_format)?)?;
+    let output = [OPENSSL_MAGIC, &salt, &ciphertext].concat();
+    format_bytes(&output, output_format)
+}
+
+/// Decrypt `openssl enc` output. Data with the `Salted__` header uses its salt; data without it is
+/// treated as `-nosalt` output.
+pub fn openssl_decrypt(input: &str, params: &OpensslEncParams, input_format: &str, output_format: &str) -> Result<String> {
+    let data = openssl_input(input, input_format)?;
+    let (salt, ciphertext) = match data.strip_prefix(OPENSSL_MAGIC) {
+        Some(rest) if rest.len() >= 8 => rest.split_at(8),
+        Some(_) => return Err(anyhow!("Truncated Salted__ header")),
+        None => (&[][..], &data[..]),
+    };
+    let plaintext = openssl_crypt(params, salt, true, ciphertext)?;
+    format_bytes(&plaintext, output_format)
+}
+
+// Keys are accepted as PEM, a JWK object, or DER given as hex or Base64.
+enum KeyMaterial {
+    Pem(String),
+    Jwk(serde_json::Value),
+    Der(Vec<u8>),
+}
+
+impl KeyMaterial {
+    fn parse(key: &str) -> Result<Self> {
+        let key = key.trim();
+        if key.is_empty() {
+            Err(anyhow!("A key is required (PEM, JWK, or DER as hex or Base64)"))
+        } else if key.starts_with("-----BEGIN") {
+            Ok(KeyMaterial::Pem(key.to_string()))
+        } else if key.starts_with('{') {
+            Ok(KeyMaterial::Jwk(serde_json::from_str(key)?))
+        } else if key.chars().all(|c| c.is_ascii_hexdigit() || c.is_whitespace() || c == ':') {
+            Ok(KeyMaterial::Der(parse_bytes(key, "hex")?))
+        } else {
+            Ok(KeyMaterial::Der(parse_bytes(key, "base64")?))
+        }
+    }
+}
+
+fn jwk_bytes(jwk: &serde_json::Value, field: &str) -> Result<Vec<u8>> {
+    let value = jwk[field].as_str().ok_or_else(|| anyhow!("JWK is missing \"{}\"", field))?;
+    Ok(URL_SAFE_NO_PAD.decode(value.trim_end_matches('='))?)
+}
+
+fn jwk_uint(jwk: &serde_json::Value, field: &str) -> Result<BigUint> {
+    Ok(BigUint::from_bytes_be(&jwk_bytes(jwk, field)?))
+}
+
+fn check_jwk_type(jwk: &serde_json::Value, kty: &str) -> Result<()> {
+    match jwk["kty"].as_str() {
+        Some(found) if found == kty => Ok(()),
+        found => Err(anyhow!("Expected a JWK with kty \"{}\", found {:?}", kty, found.unwrap_or("none"))),
+    }
+}
+
+fn rsa_private_key(key: &str) -> Result<RsaPrivateKey> {
+    let private = match KeyMaterial::parse(key)? {
+        KeyMaterial::Pem(pem) => RsaPrivateKey::from_pkcs8_pem(&pem).or_else(|_| RsaPrivateKey::from_pkcs1_pem(&pem)).ok(),
+        KeyMaterial::Der(der) => RsaPrivateKey::from_pkcs8_der(&der).or_else(|_| RsaPrivateKey::from_pkcs1_der(&der)).ok(),
+        KeyMaterial::Jwk(jwk) => {
+            check_jwk_type(&jwk, "RSA")?;
+            let primes = vec![jwk_uint(&jwk, "p")?, jwk_uint(&jwk, "q")?];
+            Some(RsaPrivateKey::from_components(jwk_uint(&jwk, "n")?, jwk_uint(&jwk, "e")?, jwk_uint(&jwk, "d")?, primes)?)
+        }
+    };
+    private.ok_or_else(|| anyhow!("Could not read an RSA private key (expected PKCS#8 or PKCS#1 PEM/DER, or a JWK)"))
+}
+
+// Public keys may also be given as the private key they belong to.
+fn rsa_public_key(key: &str) -> Result<RsaPublicKey> {
+    let public = match KeyMaterial::parse(key)? {
+        KeyMaterial::Pem(pem) => RsaPublicKey::from_public_key_pem(&pem).or_else(|_| RsaPublicKey::from_pkcs1_pem(&pem)).ok(),
+        KeyMaterial::Der(der) => RsaPublicKey::from_public_key_der(&der).or_else(|_| RsaPublicKey::from_pkcs1_der(&der)).ok(),
+        KeyMaterial::Jwk(jwk) => {
+            check_jwk_type(&jwk, "RSA")?;
+            Some(RsaPublicKey::new(jwk_uint(&jwk, "n")?, jwk_uint(&jwk, "e")?)?)
+        }
+    };
+    match public {
+        Some(public) => Ok(public),
+        None => rsa_private_key(key)
+            .map(|private| private.to_public_key())
+            .map_err(|_| anyhow!("Could not read an RSA public key (expected SPKI or PKCS#1 PEM/DER, a private key, or a JWK)")),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RsaHash {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl RsaHash {
+    fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().replace('-', "").as_str() {
+            "sha1" => Ok(RsaHash::Sha1),
+            "sha256" | "" => Ok(RsaHash::Sha256),
+            "sha384" => Ok(RsaHash::Sha384),
+            "sha512" => Ok(RsaHash::Sha512),
+            _ => Err(anyhow!("Unknown hash: {} (expected sha1, sha256, sha384 or sha512)", name)),
+        }
+    }
+
+    fn digest(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            RsaHash::Sha1 => Sha1::digest(data).to_vec(),
+            RsaHash::Sha256 => Sha256::digest(data).to_vec(),
+            RsaHash::Sha384 => Sha384::digest(data).to_vec(),
+            RsaHash::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+
+    fn pkcs1v15(self) -> Pkcs1v15Sign {
+        match self {
+            RsaHash::Sha1 => Pkcs1v15Sign::new::<Sha1>(),
+            RsaHash::Sha256 => Pkcs1v15Sign::new::<Sha256>(),
+            RsaHash::Sha384 => Pkcs1v15Sign::new::<Sha384>(),
+            RsaHash::Sha512 => Pkcs1v15Sign::new::<Sha512>(),
+        }
+    }
+
+    // PSS salt length follows the digest length, as in RFC 8017 and JWS PS256/PS384/PS512
+    fn pss(self) -> Pss {
+        match self {
+            RsaHash::Sha1 => Pss::new::<Sha1>(),
+            RsaHash::Sha256 => Pss::new::<Sha256>(),
+            RsaHash::Sha384 => Pss::new::<Sha384>(),
+            RsaHash::Sha512 => Pss::new::<Sha512>(),
+        }
+    }
+
+    fn oaep(self) -> Oaep {
+        match self {
+            RsaHash::Sha1 => Oaep::new::<Sha1>(),
+            RsaHash::Sha256 => Oaep::new::<Sha256>(),
+            RsaHash::Sha384 => Oaep::new::<Sha384>(),
+            RsaHash::Sha512 => Oaep::new::<Sha512>(),
+        }
+    }
+}
+
+fn uses_pss(scheme: &str) -> Result<bool> {
+    match scheme.trim().to_lowercase().replace(['-', '_', ' '], "").as_str() {
+        "pkcs1v15" | "pkcs1" | "" => Ok(false),
+        "pss" => Ok(true),
+        _ => Err(anyhow!("Unknown RSA signature scheme: {} (expected pkcs1v15 or pss)", scheme)),
+    }
+}
+
+fn uses_oaep(padding: &str) -> Result<bool> {
+    match padding.trim().to_lowercase().replace(['-', '_', ' '], "").as_str() {
+        "oaep" | "" => Ok(true),
+        "pkcs1v15" | "pkcs1" => Ok(false),
+        _ => Err(anyhow!("Unknown RSA padding: {} (expected oaep or pkcs1v15)", padding)),
+    }
+}
+
+fn verification_result(valid: bool) -> Result<String> {
+    Ok(if valid { "Valid" } else { "Invalid" }.to_string())
+}
+
+pub fn rsa_sign(input: &str, private_key: &str, scheme: &str, hash: &str, input_format: &str, output_format: &str) -> Result<String> {
+    let key = rsa_private_key(private_key)?;
+    let hash = RsaHash::from_name(hash)?;
+    let hashed = hash.digest(&parse_bytes(input, input_format)?);
+    let signature = if uses_pss(scheme)? {
+        key.sign_with_rng(&mut OsRng, hash.pss(), &hashed)?
+    } else {
+        key.sign(hash.pkcs1v15(), &hashed)?
+    };
+    format_bytes(&signature, output_format)
+}
+
+pub fn rsa_verify(
+    input: &str,
+    public_key: &str,
+    signature: &str,
+    scheme: &str,
+    hash: &str,
+    input_format: &str,
+    signature_format: &str,
+) -> Result<String> {
+    let key = rsa_public_key(public_key)?;
+    let hash = RsaHash::from_name(hash)?;
+    let hashed = hash.digest(&parse_bytes(input, input_format)?);
+    let signature = parse_bytes(signature, signature_format)?;
+    let verified = if uses_pss(scheme)? {
+        key.verify(hash.pss(), &hashed, &signature)
+    } else {
+        key.verify(hash.pkcs1v15(), &hashed, &signature)
+    };
+    verification_result(verified.is_ok())
+}
+
+/// RSA encryption with OAEP (the hash also drives MGF1) or PKCS#1 v1.5 padding.
+pub fn rsa_encrypt(input: &str, public_key: &str, padding: &str, hash: &str, input_format: &str, output_format: &str) -> Result<String> {
+    let key = rsa_public_key(public_key)?;
+    let data = parse_bytes(input, input_format)?;
+    let encrypted = if uses_oaep(padding)? {
+        key.encrypt(&mut OsRng, RsaHash::from_name(hash)?.oaep(), &data)?
+    } else {
+        key.encrypt(&mut OsRng, Pkcs1v15Encrypt, &data)?
+    };
+    format_bytes(&encrypted, output_format)
+}
+
+pub fn rsa_decrypt(input: &str, private_key: &str, padding: &str, hash: &str, input_format: &str, output_format: &str) -> Result<String> {
+    let key = rsa_private_key(private_key)?;
+    let data = parse_bytes(input, input_format)?;
+    let decrypted = if uses_oaep(padding)? {
+        key.decrypt(RsaHash::from_name(hash)?.oaep(), &data)?
+    } else {
+        key.decrypt(Pkcs1v15Encrypt, &data)?
+    };
+    format_bytes(&decrypted, output_format)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum EcCurve {
+    P256,
+    P384,
+    Secp256k1,
+}
+
+impl EcCurve {
+    fn from_name(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().replace('-', "").as_str() {
+            "p256" | "secp256r1" | "prime256v1" | "" => Ok(EcCurve::P256),
+            "p384" | "secp384r1" => Ok(EcCurve::P384),
+            "secp256k1" | "k256" => Ok(EcCurve::Secp256k1),
+            _ => Err(anyhow!("Unknown curve: {} (expected p256, p384 or secp256k1)", name)),
+        }
+    }
+}
+
+fn ec_secret_key<C>(key: &str) -> Result<SecretKey<C>>
+where
+    C: CurveArithmetic + AssociatedOid + JwkParameters + ValidatePublicKey,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let secret = match KeyMaterial::parse(key)? {
+        KeyMaterial::Pem(pem) => SecretKey::from_pkcs8_pem(&pem).ok().or_else(|| SecretKey::from_sec1_pem(&pem).ok()),
+        KeyMaterial::Der(der) => SecretKey::from_pkcs8_der(&der)
+            .ok()
+            .or_else(|| SecretKey::from_sec1_der(&der).ok())
+            .or_else(|| SecretKey::from_slice(&der).ok()),
+        KeyMaterial::Jwk(jwk) => Some(serde_json::from_value::<JwkEcKey>(jwk)?.to_secret_key()?),
+    };
+    secret.ok_or_else(|| anyhow!("Could not read an EC private key for this curve (expected PKCS#8 or SEC1 PEM/DER, a raw scalar, or a JWK)"))
+}
+
+fn ec_public_key<C>(key: &str) -> Result<PublicKey<C>>
+where
+    C: CurveArithmetic + AssociatedOid + JwkParameters + ValidatePublicKey,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let public = match KeyMaterial::parse(key)? {
+        KeyMaterial::Pem(pem) => PublicKey::from_public_key_pem(&pem).ok(),
+        KeyMaterial::Der(der) => PublicKey::from_public_key_der(&der).ok().or_else(|| PublicKey::from_sec1_bytes(&der).ok()),
+        KeyMaterial::Jwk(jwk) => Some(serde_json::from_value::<JwkEcKey>(jwk)?.to_public_key()?),
+    };
+    match public {
+        Some(public) => Ok(public),
+        None => ec_secret_key::<C>(key).map(|secret| secret.public_key()).map_err(|_| {
+            anyhow!("Could not read an EC public key for this curve (expected SPKI PEM/DER, a SEC1 point, a private key, or a JWK)")
+        }),
+    }
+}
+
+fn der_signature_encoding(encoding: &str) -> Result<bool> {
+    match encoding.trim().to_lowercase().as_str() {
+        "der" | "" => Ok(true),
+        "raw" | "p1363" | "jose" => Ok(false),
+        _ => Err(anyhow!("Unknown signature encoding: {} (expected der or raw)", encoding)),
+    }
+}
+
+/// ECDSA with the curve's usual hash: SHA-256 for P-256 and secp256k1, SHA-384 for P-384.
+/// Signatures are DER or raw r||s as used by JWS.
+pub fn ecdsa_sign(input: &str, private_key: &str, curve: &str, encoding: &str, input_format: &str, output_format: &str) -> Result<String> {
+    let data = parse_bytes(input, input_format)?;
+    let der = der_signature_encoding(encoding)?;
+    let signature = match EcCurve::from_name(curve)? {
+        EcCurve::P256 => {
+            let signature: p256::ecdsa::Signature = p256::ecdsa::SigningKey::from(ec_secret_key(private_key)?).sign(&data);
+            if der { signature.to_der().as_bytes().to_vec() } else { signature.to_vec() }
+        }
+        EcCurve::P384 => {
+            let signature: p384::ecdsa::Signature = p384::ecdsa::SigningKey::from(ec_secret_key(private_key)?).sign(&data);
+            if der { signature.to_der().as_bytes().to_vec() } else { signature.to_vec() }
+        }
+        EcCurve::Secp256k1 => {
+            let signature: k256::ecdsa::Signature = k256::ecdsa::SigningKey::from(ec_secret_key(private_key)?).sign(&data);
+            if der { signature.to_der().as_bytes().to_vec() } else { signature.to_vec() }
+        }
+    };
+    format_bytes(&signature, output_format)
+}
+
+// Raw r||s signatures are exactly twice the field size, so the encoding is detected rather than given.
+pub fn ecdsa_verify(input: &str, public_key: &str, signature: &str, curve: &str, input_format: &str, signature_format: &str) -> Result<String> {
+    let data = parse_bytes(input, input_format)?;
+    let signature = parse_bytes(signature, signature_format)?;
+    let valid = match EcCurve::from_name(curve)? {
+        EcCurve::P256 => {
+            let key = p256::ecdsa::VerifyingKey::from(ec_public_key(public_key)?);
+            let signature = p256::ecdsa::Signature::from_slice(&signature).or_else(|_| p256::ecdsa::Signature::from_der(&signature));
+            signature.is_ok_and(|signature| key.verify(&data, &signature).is_ok())
+        }
+        EcCurve::P384 => {
+            let key = p384::ecdsa::VerifyingKey::from(ec_public_key(public_key)?);
+            let signature = p384::ecdsa::Signature::from_slice(&signature).or_else(|_| p384::ecdsa::Signature::from_der(&signature));
+            signature.is_ok_and(|signature| key.verify(&data, &signature).is_ok())
+        }
+        EcCurve::Secp256k1 => {
+            let key = k256::ecdsa::VerifyingKey::from(ec_public_key(public_key)?);
+            let signature = k256::ecdsa::Signature::from_slice(&signature).or_else(|_| k256::ecdsa::Signature::from_der(&signature));
+            // k256 only accepts low-S (BIP 62) signatures, but other signers emit either half
+            signature.is_ok_and(|signature| key.verify(&data, &signature.normalize_s().unwrap_or(signature)).is_ok())
+        }
+    };
+    verification_result(valid)
+}
+
+fn ed25519_signing_key(key: &str) -> Result<Ed25519SigningKey> {
+    let invalid = || anyhow!("Could not read an Ed25519 private key (expected PKCS#8 PEM/DER, a 32-byte seed, or a JWK)");
+    match KeyMaterial::parse(key)? {
+        KeyMaterial::Pem(pem) => Ed25519SigningKey::from_pkcs8_pem(&pem).map_err(|_| invalid()),
+        KeyMaterial::Der(der) => match <[u8; 32]>::try_from(der.as_slice()) {
+            Ok(seed) => Ok(Ed25519SigningKey::from_bytes(&seed)),
+            Err(_) => Ed25519SigningKey::from_pkcs8_der(&der).map_err(|_| invalid()),
+        },
+        KeyMaterial::Jwk(jwk) => {
+            check_jwk_type(&jwk, "OKP")?;
+            let seed = <[u8; 32]>::try_from(jwk_bytes(&jwk, "d")?.as_slice()).map_err(|_| invalid())?;
+            Ok(Ed25519SigningKey::from_bytes(&seed))
+        }
+    }
+}
+
+fn ed25519_verifying_key(key: &str) -> Result<Ed25519VerifyingKey> {
+    let public = match KeyMaterial::parse(key)? {
+        KeyMaterial::Pem(pem) => Ed25519VerifyingKey::from_public_key_pem(&pem).ok(),
+        KeyMaterial::Der(der) => match <[u8; 32]>::try_from(der.as_slice()) {
+            Ok(point) => Some(Ed25519VerifyingKey::from_bytes(&point)?),
+            Err(_) => Ed25519VerifyingKey::from_public_key_der(&der).ok(),
+        },
+        KeyMaterial::Jwk(jwk) => {
+            check_jwk_type(&jwk, "OKP")?;
+            let point = <[u8; 32]>::try_from(jwk_bytes(&jwk, "x")?.as_slice()).map_err(|_| anyhow!("JWK \"x\" must be 32 bytes"))?;
+            Some(Ed25519VerifyingKey::from_bytes(&point)?)
+        }
+    };
+    match public {
+        Some(public) => Ok(public),
+        None => ed25519_signing_key(key).map(|private| private.verifying_key()).map_err(|_| {
+            anyhow!("Could not read an Ed25519 public key (expected SPKI PEM/DER, a 32-byte key, a private key, or a JWK)")
+        }),
+    }
+}
+
+pub fn ed25519_sign(input: &str, private_key: &str, input_format: &str, output_format: &str) -> Result<String> {
+    let key = ed25519_signing_key(private_key)?;
+    let signature = key.sign(&parse_bytes(input, input_format)?);
+    format_bytes(&signature.to_bytes(), output_format)
+}
+
+pub fn ed25519_verify(input: &str, public_key: &str, signature: &str, input_format: &str, signature_format: &str) -> Result<String> {
+    let key = ed25519_verifying_key(public_key)?;
+    let data = parse_bytes(input, input_format)?;
+    let signature = Ed25519Signature::from_slice(&parse_bytes(signature, signature_format)?);
+    verification_result(signature.is_ok_and(|signature| key.verify_strict(&data, &signature).is_ok()))
+}
+
+fn ec_keypair<C>(format: &str) -> Result<String>
+where
+    C: CurveArithmetic + AssociatedOid + JwkParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let secret = SecretKey::<C>::random(&mut OsRng);
+    if format == "jwk" {
+        keypair_jwk(serde_json::from_str(&secret.to_jwk_string())?, serde_json::from_str(&secret.public_key().to_jwk_string())?)
+    } else {
+        keypair_pem(&secret.to_pkcs8_pem(LineEnding::LF)?, &secret.public_key().to_public_key_pem(LineEnding::LF)?)
+    }
+}
+
+fn keypair_pem(private_pem: &str, public_pem: &str) -> Result<String> {
+    Ok(format!("{}{}", private_pem, public_pem.trim_end()))
+}
+
+fn keypair_jwk(private_jwk: serde_json::Value, public_jwk: serde_json::Value) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&serde_json::json!({ "private_key": private_jwk, "public_key": public_jwk }))?)
+}
+
+/// Generate an RSA, EC or Ed25519 keypair as PKCS#8/SPKI PEM (private key first) or as JWKs.
+pub fn generate_keypair(algorithm: &str, bits: usize, format: &str) -> Result<String> {
+    let format = format.trim().to_lowercase();
+    if format != "pem" && format != "jwk" {
+        return Err(anyhow!("Unknown key format: {} (expected pem or jwk)", format));
+    }
+    match algorithm.trim().to_lowercase().as_str() {
+        "rsa" => {
+            if !(MIN_RSA_BITS..=MAX_RSA_BITS).contains(&bits) {
+                return Err(anyhow!("RSA key size must be between {} and {} bits", MIN_RSA_BITS, MAX_RSA_BITS));
+            }
+            let private = RsaPrivateKey::new(&mut OsRng, bits)?;
+            let public = private.to_public_key();
+            if format == "jwk" {
+                let encode = |n: &BigUint| URL_SAFE_NO_PAD.encode(n.to_bytes_be());
+                let public_jwk = serde_json::json!({ "kty": "RSA", "n": encode(public.n()), "e": encode(public.e()) });
+                let mut private_jwk = public_jwk.clone();
+                private_jwk["d"] = encode(private.d()).into();
+                private_jwk["p"] = encode(&private.primes()[0]).into();
+                private_jwk["q"] = encode(&private.primes()[1]).into();
+                keypair_jwk(private_jwk, public_jwk)
+            } else {
+                keypair_pem(&private.to_pkcs8_pem(LineEnding::LF)?, &public.to_public_key_pem(LineEnding::LF)?)
+            }
+        }
+        "ed25519" => {
+            let private = Ed25519SigningKey::generate(&mut OsRng);
+            if format == "jwk" {
+                let x = URL_SAFE_NO_PAD.encode(private.verifying_key().as_bytes());
+                let public_jwk = serde_json::json!({ "kty": "OKP", "crv": "Ed25519", "x": x });
+                let mut private_jwk = public_jwk.clone();
+                private_jwk["d"] = URL_SAFE_NO_PAD.encode(private.to_bytes()).into();
+                keypair_jwk(private_jwk, public_jwk)
+            } else {
+                let private_pem = private.to_pkcs8_pem(LineEnding::LF).map_err(|e| anyhow!("{}", e))?;
+                let public_pem = private.verifying_key().to_public_key_pem(LineEnding::LF).map_err(|e| anyhow!("{}", e))?;
+                keypair_pem(&private_pem, &public_pem)
+            }
+        }
+        curve => match EcCurve::from_name(curve).map_err(|_| {
+            anyhow!("Unknown key algorithm: {} (expected rsa, p256, p384, secp256k1 or ed25519)", algorithm)
+        })? {
+            EcCurve::P256 => ec_keypair::<p256::NistP256>(&format),
+            EcCurve::P384 => ec_keypair::<p384::NistP384>(&format),
+            EcCurve::Secp256k1 => ec_keypair::<k256::Secp256k1>(&format),
+        },
+    }
+}
+
 pub fn reverse_words(input: &str) -> Result<String> {
     let words: Vec<&str> = input.split_whitespace().collect();
     let reversed: Vec<&str> = words.into_iter().rev().collect();
@@ -78,4 +1372,330 @@ mod tests {
         let double_atbash = atbash_cipher(&result).unwrap();
         assert_eq!(double_atbash, "ABC");
     }
+
+    #[test]
+    fn test_vigenere_and_beaufort() {
+        assert_eq!(vigenere_cipher("Attack at dawn!", "LEMON", false).unwrap(), "Lxfopv ef rnhr!");
+        assert_eq!(vigenere_cipher("Lxfopv ef rnhr!", "lemon", true).unwrap(), "Attack at dawn!");
+        assert!(vigenere_cipher("abc", "123", false).is_err());
+        let beaufort = beaufort_cipher("Defend the east", "FORTIFICATION").unwrap();
+        assert_eq!(beaufort, "Ckmpvc pvw piwu");
+        assert_eq!(beaufort_cipher(&beaufort, "FORTIFICATION").unwrap(), "Defend the east");
+    }
+
+    #[test]
+    fn test_affine_and_rail_fence() {
+        assert_eq!(affine_cipher("Affine cipher", 5, 8, false).unwrap(), "Ihhwvc swfrcp");
+        assert_eq!(affine_cipher("Ihhwvc swfrcp", 5, 8, true).unwrap(), "Affine cipher");
+        assert!(affine_cipher("abc", 13, 1, false).is_err());
+
+        let encoded = rail_fence_cipher("WEAREDISCOVEREDFLEEATONCE", 3, 0, false).unwrap();
+        assert_eq!(encoded, "WECRLTEERDSOEEFEAOCAIVDEN");
+        assert_eq!(rail_fence_cipher(&encoded, 3, 0, true).unwrap(), "WEAREDISCOVEREDFLEEATONCE");
+        let plain = "We are discovered. Flee at once!";
+        let shifted = rail_fence_cipher(plain, 4, 3, false).unwrap();
+        assert_eq!(rail_fence_cipher(&shifted, 4, 3, true).unwrap(), plain);
+    }
+
+    #[test]
+    fn test_bacon_cipher() {
+        assert_eq!(bacon_cipher("Hi!", "complete", false).unwrap(), "AABBBABAAA!");
+        assert_eq!(bacon_cipher("AABBBABAAA!", "complete", true).unwrap(), "HI!");
+        assert_eq!(bacon_cipher("JV", "standard", false).unwrap(), "ABAAABAABB");
+        assert!(bacon_cipher("AAB", "standard", true).is_err());
+    }
+
+    #[test]
+    fn test_playfair_and_bifid() {
+        let encoded = playfair_cipher("Hide the gold in the tree stump", "playfair example", false).unwrap();
+        let letters: String = encoded.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+        assert_eq!(letters.to_uppercase(), "BMODZBXDNABEKUDMUIXMMOUVIF");
+        assert_eq!(playfair_cipher("BMODZBXDNABEKUDMUIXMMOUVIF", "playfair example", true).unwrap(), "HIDETHEGOLDINTHETREXESTUMP");
+
+        let square = "BGWKZQPNDSIOAXEFCLUMTHYVR";
+        assert_eq!(bifid_cipher("Flee at once", square, false).unwrap(), "Uaeo lw rins");
+        assert_eq!(bifid_cipher("Uaeo lw rins", square, true).unwrap(), "Flee at once");
+    }
+
+    #[test]
+    fn test_substitution_and_rotations() {
+        let key = "zyxwvutsrqponmlkjihgfedcba";
+        assert_eq!(substitution_cipher("Hello, World", "abcdefghijklmnopqrstuvwxyz", key, false).unwrap(), "Svool, Dliow");
+        assert_eq!(substitution_cipher("Svool, Dliow", "abcdefghijklmnopqrstuvwxyz", key, true).unwrap(), "Hello, World");
+        assert!(substitution_cipher("x", "abc", "ab", false).is_err());
+
+        assert_eq!(rot47("Hello, World!").unwrap(), "w6==@[ (@C=5P");
+        assert_eq!(rot47("w6==@[ (@C=5P").unwrap(), "Hello, World!");
+        let rotated = rot8000("The Quick Brown Fox Jumped Over The Lazy Dog.").unwrap();
+        assert_eq!(rotated, "籝籱籮 籚籾籲籬籴 籋类籸粀籷 籏籸粁 籓籾籶籹籮籭 籘籿籮类 籝籱籮 籕籪粃粂 籍籸籰簷");
+        assert_eq!(rot8000(&rotated).unwrap(), "The Quick Brown Fox Jumped Over The Lazy Dog.");
+    }
+
+    #[test]
+    fn test_xor_with_key_formats() {
+        assert_eq!(xor("Hello", "01", "hex", "standard", false, "utf8", "utf8").unwrap(), "Idmmn");
+        let encrypted = xor("secret", "key", "utf8", "standard", false, "utf8", "base64").unwrap();
+        assert_eq!(xor(&encrypted, "a2V5", "base64", "standard", false, "base64", "utf8").unwrap(), "secret");
+        assert_eq!(xor("41 00 42", "41", "hex", "standard", true, "hex", "hex").unwrap(), "410003");
+        assert!(xor("abc", "00", "hex", "cascade", false, "utf8", "hex").is_err());
+    }
+
+    #[test]
+    fn test_xor_differential_schemes() {
+        let plain = "The quick brown fox jumps over the lazy dog";
+        let encrypted = xor(plain, "1337", "hex", "input_differential", false, "utf8", "hex").unwrap();
+        assert_ne!(encrypted, xor(plain, "1337", "hex", "standard", false, "utf8", "hex").unwrap());
+        assert_eq!(xor(&encrypted, "1337", "hex", "output_differential", false, "hex", "utf8").unwrap(), plain);
+    }
+
+    #[test]
+    fn test_xor_brute_force() {
+        let encrypted = xor("This is a secret message", "5a", "hex", "standard", false, "utf8", "hex").unwrap();
+        // The crib is case-insensitive, so the case-flipped key 0x7a matches too but ranks lower
+        let result = xor_brute_force(&encrypted, "hex", 1, 100, 0, "SECRET", 10).unwrap();
+        assert_eq!(result.lines().next().unwrap(), "Key 0x5a (100.0% printable): This is a secret message");
+        assert!(result.lines().nth(1).unwrap().starts_with("Key 0x7a (83.3% printable): tHIS\\u{0}IS"));
+
+        let ranked = xor_brute_force(&encrypted, "hex", 1, 100, 0, "", 3).unwrap();
+        assert_eq!(ranked.lines().count(), 3);
+        assert!(ranked.starts_with("Key 0x5a (100.0% printable): This is a secret message"));
+
+        let encrypted = xor("MZ This program cannot be run in DOS mode", "a1b2", "hex", "standard", false, "utf8", "hex").unwrap();
+        let result = xor_brute_force(&encrypted, "hex", 2, 100, 0, "cannot be run", 1).unwrap();
+        assert!(result.starts_with("Key 0xa1b2 (100.0% printable): MZ This program"));
+        assert!(xor_brute_force(&encrypted, "hex", 3, 100, 0, "", 1).is_err());
+
+        // `top` is capped rather than listing all 65536 two-byte keys
+        assert_eq!(xor_brute_force(&encrypted, "hex", 2, 16, 0, "", usize::MAX).unwrap().lines().count(), 100);
+    }
+
+    const SUNSCREEN: &str = "Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+    fn block_params<'a>(key: &'a str, iv: &'a str, mode: &'a str, padding: &'a str, decrypt: bool) -> BlockCipherParams<'a> {
+        BlockCipherParams { key, iv, mode, padding, decrypt }
+    }
+
+    #[test]
+    fn test_chacha20_rfc8439() {
+        let key = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+        let encrypted = chacha20_cipher(SUNSCREEN, key, "000000000000004a00000000", 1, "utf8", "hex").unwrap();
+        assert_eq!(
+            encrypted,
+            "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0bf91b65c5524733ab8f593dabcd62b357\
+             1639d624e65152ab8f530c359f0861d807ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736\
+             5af90bbf74a35be6b40b8eedf2785e42874d"
+        );
+        assert_eq!(chacha20_cipher(&encrypted, key, "000000000000004a00000000", 1, "hex", "utf8").unwrap(), SUNSCREEN);
+        assert!(chacha20_cipher("abc", key, "0000", 0, "utf8", "hex").is_err());
+        assert!(chacha20_cipher("abc", "0011", "000000000000004a00000000", 0, "utf8", "hex").is_err());
+    }
+
+    #[test]
+    fn test_salsa20_and_rc4_keystreams() {
+        let zeros = "00".repeat(64);
+        let key = format!("80{}", "00".repeat(31));
+        assert_eq!(
+            salsa20_cipher(&zeros, &key, "0000000000000000", 0, "hex", "hex").unwrap(),
+            "e3be8fdd8beca2e3ea8ef9475b29a6e7003951e1097a5c38d23b7a5fad9f6844\
+             b22c97559e2723c7cbbd3fe4fc8d9a0744652a83e72a9c461876af4d7ef1a117"
+        );
+        // RFC 6229 test vector for a 40-bit key, offsets 0 and 256
+        let zeros = "00".repeat(16);
+        assert_eq!(rc4_cipher(&zeros, "0102030405", "hex", 0, "hex", "hex").unwrap(), "b2396305f03dc027ccc3524a0a1118a8");
+        assert_eq!(rc4_cipher(&zeros, "0102030405", "hex", 256, "hex", "hex").unwrap(), "1cfcf62b03eddb641d77dfcf7f8d8c93");
+        assert_eq!(rc4_cipher("Plaintext", "Key", "utf8", 0, "utf8", "hex").unwrap(), "bbf316e8d940af0ad3");
+        assert!(rc4_cipher("abc", "", "utf8", 0, "utf8", "hex").is_err());
+    }
+
+    #[test]
+    fn test_des_and_triple_des() {
+        let params = block_params("133457799BBCDFF1", "", "ecb", "none", false);
+        assert_eq!(des_cipher("0123456789ABCDEF", &params, "hex", "hex").unwrap(), "85e813540f0ab405");
+
+        // NIST SP 800-67 three-key example
+        let key = "0123456789ABCDEF23456789ABCDEF01456789ABCDEF0123";
+        let params = block_params(key, "", "ecb", "none", false);
+        let encrypted = triple_des_cipher("The qufck brown fox jump", &params, "utf8", "hex").unwrap();
+        assert_eq!(encrypted, "a826fd8ce53b855fcce21c8112256fe668d5c05dd9b6b900");
+        let params = block_params(key, "", "ecb", "none", true);
+        assert_eq!(triple_des_cipher(&encrypted, &params, "hex", "utf8").unwrap(), "The qufck brown fox jump");
+
+        let params = block_params("0123456789ABCDEF", "", "ecb", "none", false);
+        assert!(des_cipher("short", &params, "utf8", "hex").is_err());
+        assert!(triple_des_cipher("12345678", &params, "utf8", "hex").is_err());
+    }
+
+    #[test]
+    fn test_blowfish_vectors_and_modes() {
+        let params = block_params("0000000000000000", "", "ecb", "none", false);
+        assert_eq!(blowfish_cipher("0000000000000000", &params, "hex", "hex").unwrap(), "4ef997456198dd78");
+        let params = block_params("FFFFFFFFFFFFFFFF", "", "ecb", "none", false);
+        assert_eq!(blowfish_cipher("FFFFFFFFFFFFFFFF", &params, "hex", "hex").unwrap(), "51866fd5b85ecb8a");
+
+        // Eric Young's CBC test data, zero-padded to a whole number of blocks
+        let params = block_params("0123456789ABCDEFF0E1D2C3B4A59687", "FEDCBA9876543210", "cbc", "none", false);
+        let data = "37363534333231204E6F77206973207468652074696D6520666F722000000000";
+        assert_eq!(
+            blowfish_cipher(data, &params, "hex", "hex").unwrap(),
+            "6b77b4d63006dee605b156e27403979358deb9e7154616d959f1652bd5ff92cc"
+        );
+
+        for mode in ["ecb", "cbc", "cfb", "ofb", "ctr"] {
+            let encrypt = block_params("6b657931", "0102030405060708", mode, "pkcs7", false);
+            let decrypt = block_params("6b657931", "0102030405060708", mode, "pkcs7", true);
+            let encrypted = blowfish_cipher("Attack at dawn!", &encrypt, "utf8", "hex").unwrap();
+            assert_eq!(blowfish_cipher(&encrypted, &decrypt, "hex", "utf8").unwrap(), "Attack at dawn!", "{}", mode);
+        }
+        assert!(blowfish_cipher("abc", &block_params("6b6579", "", "ecb", "pkcs7", false), "utf8", "hex").is_err());
+        assert!(blowfish_cipher("abc", &block_params("6b657931", "0102", "cbc", "pkcs7", false), "utf8", "hex").is_err());
+    }
+
+    #[test]
+    fn test_xchacha20_poly1305() {
+        let key = "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f";
+        let nonce = "404142434445464748494a4b4c4d4e4f5051525354555657";
+        let aad = "50515253c0c1c2c3c4c5c6c7";
+        let sealed = xchacha20_poly1305(SUNSCREEN, key, nonce, aad, false, "utf8", "hex").unwrap();
+        assert_eq!(
+            sealed,
+            "bd6d179d3e83d43b9576579493c0e939572a1700252bfaccbed2902c21396cbb731c7f1b0b4aa6440bf3a82f4eda7e39\
+             ae64c6708c54c216cb96b72e1213b4522f8c9ba40db5d945b11b69b982c1bb9e3f3fac2bc369488f76b2383565d3fff9\
+             21f9664c97637da9768812f615c68b13b52ec0875924c1c7987947deafd8780acf49"
+        );
+        assert_eq!(xchacha20_poly1305(&sealed, key, nonce, aad, true, "hex", "utf8").unwrap(), SUNSCREEN);
+        assert!(xchacha20_poly1305(&sealed, key, nonce, "", true, "hex", "utf8").is_err());
+    }
+
+    fn split_keypair(pem: &str) -> (String, String) {
+        let split = pem.find("-----BEGIN PUBLIC KEY-----").unwrap();
+        (pem[..split].to_string(), pem[split..].to_string())
+    }
+
+    fn openssl_params<'a>(cipher: &'a str, kdf: &'a str, digest: &'a str, iterations: u32) -> OpensslEncParams<'a> {
+        OpensslEncParams { password: "secret", cipher, kdf, digest, iterations }
+    }
+
+    #[test]
+    fn test_openssl_enc_compatibility() {
+        // Produced by `openssl enc -a -pass pass:secret` with the flags noted
+        let pbkdf2 = openssl_params("aes-256-cbc", "pbkdf2", "sha256", 10_000);
+        let legacy = openssl_params("aes-128-cbc", "legacy", "md5", 1);
+        assert_eq!(openssl_decrypt("U2FsdGVkX1/lHE2X3R9aV+iCFzDdulTWjeDvA3UfsH8=", &pbkdf2, "base64", "utf8").unwrap(), "attack at dawn");
+        assert_eq!(openssl_decrypt("U2FsdGVkX1+BqTqAX/PoCeZ8\nbongs1aNUL/ZlBAes64=", &legacy, "base64", "utf8").unwrap(), "attack at dawn");
+
+        // -S 0102030405060708, with and without -md sha512 -iter 1000
+        let encrypted = openssl_encrypt("attack at dawn", &pbkdf2, "0102030405060708", "utf8", "base64").unwrap();
+        assert_eq!(encrypted, "U2FsdGVkX18BAgMEBQYHCEJhGF4Jw/EARYRsfYy8Dzk=");
+        let sha512 = openssl_params("aes-192-cbc", "pbkdf2", "sha512", 1000);
+        let encrypted = openssl_encrypt("attack at dawn", &sha512, "a1a2a3a4a5a6a7a8", "utf8", "base64").unwrap();
+        assert_eq!(encrypted, "U2FsdGVkX1+hoqOkpaanqIANNna8PLpNJEYlvH0fpBc=");
+        let ecb = openssl_params("aes-256-ecb", "pbkdf2", "sha256", 10_000);
+        let encrypted = openssl_encrypt("attack at dawn", &ecb, "0102030405060708", "utf8", "base64").unwrap();
+        assert_eq!(encrypted, "U2FsdGVkX18BAgMEBQYHCJwobU6H47/9ROVD3GGmZC0=");
+        let ctr = openssl_params("aes-128-ctr", "pbkdf2", "sha256", 10_000);
+        let encrypted = openssl_encrypt("attack at dawn", &ctr, "0102030405060708", "utf8", "hex").unwrap();
+        assert_eq!(encrypted, "53616c7465645f5f0102030405060708bffb9be3fc75fdd9e66ef81b891b");
+    }
+
+    #[test]
+    fn test_openssl_enc_round_trip_and_errors() {
+        let params = openssl_params("aes-256-cfb", "pbkdf2", "sha1", 1000);
+        let encrypted = openssl_encrypt("round trip", &params, "", "utf8", "hex").unwrap();
+        assert!(encrypted.starts_with("53616c7465645f5f"));
+        assert_eq!(openssl_decrypt(&encrypted, &params, "hex", "utf8").unwrap(), "round trip");
+
+        let wrong = OpensslEncParams { password: "wrong", ..openssl_params("aes-256-cbc", "pbkdf2", "sha256", 10_000) };
+        assert!(openssl_decrypt("U2FsdGVkX1/lHE2X3R9aV+iCFzDdulTWjeDvA3UfsH8=", &wrong, "base64", "utf8").is_err());
+        assert!(openssl_encrypt("x", &openssl_params("des-cbc", "pbkdf2", "sha256", 1), "", "utf8", "hex").is_err());
+        for cipher in ["aes-256-cbc-foo", "aes-128-ctr-anything"] {
+            assert!(openssl_encrypt("x", &openssl_params(cipher, "pbkdf2", "sha256", 1), "", "utf8", "hex").is_err(), "{}", cipher);
+        }
+        assert!(openssl_encrypt("x", &params, "0102", "utf8", "hex").is_err());
+    }
+
+    #[test]
+    fn test_rsa_sign_verify_and_encrypt() {
+        let keypair = generate_keypair("rsa", 1024, "jwk").unwrap();
+        let keys: serde_json::Value = serde_json::from_str(&keypair).unwrap();
+        let (private_key, public_key) = (keys["private_key"].to_string(), keys["public_key"].to_string());
+
+        for scheme in ["pkcs1v15", "pss"] {
+            let signature = rsa_sign("webhook body", &private_key, scheme, "sha256", "utf8", "base64").unwrap();
+            assert_eq!(rsa_verify("webhook body", &public_key, &signature, scheme, "sha256", "utf8", "base64").unwrap(), "Valid");
+            assert_eq!(rsa_verify("webhook body!", &public_key, &signature, scheme, "sha256", "utf8", "base64").unwrap(), "Invalid");
+        }
+        // PKCS#1 v1.5 signatures are deterministic
+        assert_eq!(
+            rsa_sign("abc", &private_key, "pkcs1v15", "sha1", "utf8", "hex").unwrap(),
+            rsa_sign("abc", &private_key, "pkcs1v15", "sha1", "utf8", "hex").unwrap()
+        );
+
+        for padding in ["oaep", "pkcs1v15"] {
+            let encrypted = rsa_encrypt("secret", &public_key, padding, "sha256", "utf8", "hex").unwrap();
+            assert_eq!(rsa_decrypt(&encrypted, &private_key, padding, "sha256", "hex", "utf8").unwrap(), "secret");
+        }
+        assert!(rsa_sign("abc", &public_key, "pkcs1v15", "sha256", "utf8", "hex").is_err());
+        assert!(generate_keypair("rsa", 512, "pem").is_err());
+    }
+
+    #[test]
+    fn test_ecdsa_curves_and_encodings() {
+        for curve in ["p256", "p384", "secp256k1"] {
+            let (private_key, public_key) = split_keypair(&generate_keypair(curve, 0, "pem").unwrap());
+            for encoding in ["der", "raw"] {
+                let signature = ecdsa_sign("payload", &private_key, curve, encoding, "utf8", "hex").unwrap();
+                assert_eq!(ecdsa_verify("payload", &public_key, &signature, curve, "utf8", "hex").unwrap(), "Valid", "{}", curve);
+                assert_eq!(ecdsa_verify("Payload", &public_key, &signature, curve, "utf8", "hex").unwrap(), "Invalid");
+            }
+        }
+
+        let keys: serde_json::Value = serde_json::from_str(&generate_keypair("p256", 0, "jwk").unwrap()).unwrap();
+        assert_eq!(keys["public_key"]["crv"], "P-256");
+        let signature = ecdsa_sign("payload", &keys["private_key"].to_string(), "p256", "raw", "utf8", "base64").unwrap();
+        let verified = ecdsa_verify("payload", &keys["public_key"].to_string(), &signature, "p256", "utf8", "base64");
+        assert_eq!(verified.unwrap(), "Valid");
+        assert!(ecdsa_verify("payload", &keys["public_key"].to_string(), &signature, "p384", "utf8", "base64").is_err());
+    }
+
+    #[test]
+    fn test_ecdsa_secp256k1_high_s() {
+        let (private_key, public_key) = split_keypair(&generate_keypair("secp256k1", 0, "pem").unwrap());
+        let signature = ecdsa_sign("payload", &private_key, "secp256k1", "raw", "utf8", "hex").unwrap();
+        let signature = k256::ecdsa::Signature::from_slice(&hex::decode(signature).unwrap()).unwrap();
+        let (r, s) = signature.split_scalars();
+        let high = k256::ecdsa::Signature::from_scalars(r, -s).unwrap();
+        assert!(high.normalize_s().is_some());
+
+        for encoded in [hex::encode(high.to_bytes()), hex::encode(high.to_der())] {
+            assert_eq!(ecdsa_verify("payload", &public_key, &encoded, "secp256k1", "utf8", "hex").unwrap(), "Valid");
+            assert_eq!(ecdsa_verify("Payload", &public_key, &encoded, "secp256k1", "utf8", "hex").unwrap(), "Invalid");
+        }
+    }
+
+    #[test]
+    fn test_ed25519_rfc8032() {
+        // RFC 8032 section 7.1, tests 1 and 2
+        let secret = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let signature = ed25519_sign("", secret, "hex", "hex").unwrap();
+        assert_eq!(
+            signature,
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        );
+        let public = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+        assert_eq!(ed25519_verify("", public, &signature, "hex", "hex").unwrap(), "Valid");
+
+        let secret = "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb";
+        let signature = ed25519_sign("72", secret, "hex", "hex").unwrap();
+        assert_eq!(
+            signature,
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"
+        );
+        let public = "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+        assert_eq!(ed25519_verify("72", public, &signature, "hex", "hex").unwrap(), "Valid");
+        assert_eq!(ed25519_verify("73", public, &signature, "hex", "hex").unwrap(), "Invalid");
+
+        let (private_key, public_key) = split_keypair(&generate_keypair("ed25519", 0, "pem").unwrap());
+        let signature = ed25519_sign("hello", &private_key, "utf8", "base64").unwrap();
+        assert_eq!(ed25519_verify("hello", &public_key, &signature, "utf8", "base64").unwrap(), "Valid");
+    }
 }
@@ -1,8 +1,26 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use base64::{engine::general_purpose::STANDARD, Engine};
-use percent_encoding::{utf8_percent_encode, percent_decode_str, AsciiSet, CONTROLS};
+use percent_encoding::{utf8_percent_encode, percent_decode_str, AsciiSet, CONTROLS, NON_ALPHANUMERIC};
+use std::collections::HashMap;
+use std::sync::OnceLock;
 
 const FRAGMENT: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'<').add(b'>').add(b'`');
+const QUERY: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'<').add(b'>');
+const PATH: &AsciiSet = &QUERY.add(b'?').add(b'`').add(b'{').add(b'}');
+// Matches JavaScript's encodeURIComponent
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+// Stop recursive decoding after this many layers
+const MAX_URL_DECODE_DEPTH: usize = 16;
 
 pub fn base64_encode(input: &str) -> Result<String> {
     Ok(STANDARD.encode(input.as_bytes()))
@@ -13,36 +31,158 @@ pub fn base64_decode(input: &str) -> Result<String> {
     Ok(String::from_utf8(decoded)?)
 }
 
-pub fn url_encode(input: &str) -> Result<String> {
-    Ok(utf8_percent_encode(input, FRAGMENT).to_string())
+pub fn url_encode(input: &str, encode_set: &str) -> Result<String> {
+    let result = match encode_set.to_lowercase().as_str() {
+        "component" => utf8_percent_encode(input, COMPONENT).to_string(),
+        "path" => utf8_percent_encode(input, PATH).to_string(),
+        "query" => utf8_percent_encode(input, QUERY).to_string(),
+        "fragment" => utf8_percent_encode(input, FRAGMENT).to_string(),
+        "form" => url::form_urlencoded::byte_serialize(input.as_bytes()).collect(),
+        "all" => input.bytes().map(|b| format!("%{:02X}", b)).collect(),
+        other => {
+            return Err(anyhow!(
+                "Unknown encode set: {} (expected component, path, query, fragment, form or all)",
+                other
+            ))
+        }
+    };
+    Ok(result)
 }
 
-pub fn url_decode(input: &str) -> Result<String> {
-    Ok(percent_decode_str(input).decode_utf8()?.to_string())
+pub fn url_decode(input: &str, plus_as_space: bool, recursive: bool) -> Result<String> {
+    let mut current = input.to_string();
+    for depth in 0..MAX_URL_DECODE_DEPTH {
+        // Only a literal '+' in the input means space; one decoded from %2B stays a plus
+        let unplussed = if plus_as_space && depth == 0 { current.replace('+', " ") } else { current.clone() };
+        let decoded = percent_decode_str(&unplussed).decode_utf8()?.to_string();
+        if !recursive || decoded == current {
+            return Ok(decoded);
+        }
+        current = decoded;
+    }
+    Ok(current)
 }
 
-pub fn html_encode(input: &str) -> Result<String> {
-    let result = input
-        .chars()
-        .map(|c| match c {
-            '<' => "&lt;".to_string(),
-            '>' => "&gt;".to_string(),
-            '&' => "&amp;".to_string(),
-            '"' => "&quot;".to_string(),
-            '\'' => "&#x27;".to_string(),
-            _ => c.to_string(),
-        })
-        .collect();
+// HTML5 replacements for numeric references in the C1 control range (0x80..=0x9F)
+const C1_REPLACEMENTS: [u32; 32] = [
+    0x20ac, 0x81, 0x201a, 0x0192, 0x201e, 0x2026, 0x2020, 0x2021,
+    0x02c6, 0x2030, 0x0160, 0x2039, 0x0152, 0x8d, 0x017d, 0x8f,
+    0x90, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+    0x02dc, 0x2122, 0x0161, 0x203a, 0x0153, 0x9d, 0x017e, 0x0178,
+];
+
+// Longest HTML5 entity name, "CounterClockwiseContourIntegral;"
+const MAX_ENTITY_LENGTH: usize = 32;
+
+fn named_entities() -> &'static HashMap<&'static str, &'static str> {
+    static TABLE: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    TABLE.get_or_init(|| entities::ENTITIES.iter().map(|e| (&e.entity[1..], e.characters)).collect())
+}
+
+fn entity_names() -> &'static HashMap<char, &'static str> {
+    static TABLE: OnceLock<HashMap<char, &'static str>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut names: HashMap<char, &'static str> = HashMap::new();
+        for entity in entities::ENTITIES.iter().filter(|e| e.entity.ends_with(';')) {
+            let mut chars = entity.characters.chars();
+            let (Some(ch), None) = (chars.next(), chars.next()) else { continue };
+            // Prefer the shortest name, then lowercase (&amp; over &AMP;)
+            let rank = |name: &str| (name.len(), name.starts_with(|c: char| c.is_ascii_uppercase()));
+            let name = entity.entity;
+            names
+                .entry(ch)
+                .and_modify(|current| if rank(name) < rank(current) { *current = name })
+                .or_insert(name);
+        }
+        names
+    })
+}
+
+fn numeric_reference_char(code_point: u32) -> char {
+    match code_point {
+        0x80..=0x9f => char::from_u32(C1_REPLACEMENTS[(code_point - 0x80) as usize]).unwrap(),
+        0 => char::REPLACEMENT_CHARACTER,
+        _ => char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER),
+    }
+}
+
+// Decode the reference following an '&', returning the text and the number of bytes consumed
+fn decode_reference(reference: &str) -> Option<(String, usize)> {
+    if let Some(numeric) = reference.strip_prefix('#') {
+        let (prefix_len, radix) = if numeric.starts_with(['x', 'X']) { (1, 16) } else { (0, 10) };
+        let digits_len = numeric[prefix_len..].bytes().take_while(|b| (*b as char).is_digit(radix)).count();
+        if digits_len == 0 {
+            return None;
+        }
+        let digits = &numeric[prefix_len..prefix_len + digits_len];
+        let mut consumed = 1 + prefix_len + digits_len;
+        if reference[consumed..].starts_with(';') {
+            consumed += 1;
+        }
+        let code_point = u32::from_str_radix(digits, radix).unwrap_or(u32::MAX);
+        return Some((numeric_reference_char(code_point).to_string(), consumed));
+    }
+
+    let name_len = reference
+        .bytes()
+        .take(MAX_ENTITY_LENGTH)
+        .take_while(|b| b.is_ascii_alphanumeric())
+        .count();
+    let table = named_entities();
+    if reference[name_len..].starts_with(';') {
+        if let Some(characters) = table.get(&reference[..=name_len]) {
+            return Some((characters.to_string(), name_len + 1));
+        }
+    }
+    // Legacy entities such as "&amp" and "&copy" are recognised without a semicolon
+    (1..=name_len)
+        .rev()
+        .find_map(|len| table.get(&reference[..len]).map(|characters| (characters.to_string(), len)))
+}
+
+pub fn html_encode(input: &str, mode: &str) -> Result<String> {
+    let mode = mode.to_lowercase();
+    if !matches!(mode.as_str(), "minimal" | "non_ascii" | "all") {
+        return Err(anyhow!("Unknown HTML encode mode: {} (expected minimal, non_ascii or all)", mode));
+    }
+
+    let mut result = String::with_capacity(input.len());
+    for c in input.chars() {
+        match (mode.as_str(), c) {
+            (_, '<') => result.push_str("&lt;"),
+            (_, '>') => result.push_str("&gt;"),
+            (_, '&') => result.push_str("&amp;"),
+            (_, '"') => result.push_str("&quot;"),
+            (_, '\'') => result.push_str("&#x27;"),
+            ("minimal", _) => result.push(c),
+            ("non_ascii", _) if c.is_ascii() => result.push(c),
+            ("non_ascii", _) => result.push_str(&format!("&#x{:x};", c as u32)),
+            _ => match entity_names().get(&c) {
+                Some(name) => result.push_str(name),
+                None => result.push_str(&format!("&#x{:x};", c as u32)),
+            },
+        }
+    }
     Ok(result)
 }
 
 pub fn html_decode(input: &str) -> Result<String> {
-    let result = input
-        .replace("&lt;", "<")
-        .replace("&gt;", ">")
-        .replace("&amp;", "&")
-        .replace("&quot;", "\"")
-        .replace("&#x27;", "'");
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(pos) = rest.find('&') {
+        result.push_str(&rest[..pos]);
+        match decode_reference(&rest[pos + 1..]) {
+            Some((decoded, consumed)) => {
+                result.push_str(&decoded);
+                rest = &rest[pos + 1 + consumed..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[pos + 1..];
+            }
+        }
+    }
+    result.push_str(rest);
     Ok(result)
 }
 
@@ -50,11 +190,240 @@ pub fn hex_encode(input: &str) -> Result<String> {
     Ok(hex::encode(input.as_bytes()))
 }
 
+/// Decode hex, ignoring whitespace, `:`, `,`, `;` and `-` delimiters and `0x`/`\x` prefixes.
+pub fn hex_decode_bytes(input: &str) -> Result<Vec<u8>> {
+    let delimiters = regex::Regex::new(r"(?i)0x|\\x|[\s,:;-]").unwrap();
+    let cleaned = delimiters.replace_all(input, "");
+    Ok(hex::decode(cleaned.as_ref())?)
+}
+
 pub fn hex_decode(input: &str) -> Result<String> {
-    let decoded = hex::decode(input.trim())?;
+    let decoded = hex_decode_bytes(input)?;
     Ok(String::from_utf8(decoded)?)
 }
 
+/// Decode RFC 4648 Base32, case-insensitively and ignoring whitespace, `-` separators and `=` padding.
+pub fn base32_decode_bytes(input: &str) -> Result<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for c in input.chars().filter(|c| !c.is_whitespace() && !matches!(c, '-' | '=')) {
+        let value = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u32 - 'A' as u32,
+            c @ '2'..='7' => c as u32 - '2' as u32 + 26,
+            _ => return Err(anyhow!("Invalid Base32 character: {:?}", c)),
+        };
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Ok(output)
+}
+
+/// Turn operation input into bytes, reading it as UTF-8 text, Latin-1 text, hex or Base64.
+pub fn parse_bytes(input: &str, format: &str) -> Result<Vec<u8>> {
+    match format.to_lowercase().as_str() {
+        "utf8" | "utf-8" | "text" | "" => Ok(input.as_bytes().to_vec()),
+        "latin1" => input
+            .chars()
+            .map(|c| u8::try_from(c).map_err(|_| anyhow!("Character {:?} is outside Latin-1", c)))
+            .collect(),
+        "hex" => hex_decode_bytes(input),
+        "base64" => Ok(STANDARD.decode(input.trim())?),
+        other => Err(anyhow!("Unknown input format: {} (expected utf8, latin1, hex or base64)", other)),
+    }
+}
+
+/// Render bytes as operation output: strict UTF-8 text, lossy UTF-8, Latin-1, hex or Base64.
+pub fn format_bytes(bytes: &[u8], format: &str) -> Result<String> {
+    match format.to_lowercase().as_str() {
+        "utf8" | "utf-8" | "text" | "" => Ok(String::from_utf8(bytes.to_vec())?),
+        "lossy" => Ok(String::from_utf8_lossy(bytes).into_owned()),
+        "latin1" => Ok(bytes.iter().map(|&b| b as char).collect()),
+        "hex" => Ok(hex::encode(bytes)),
+        "base64" => Ok(STANDARD.encode(bytes)),
+        other => Err(anyhow!("Unknown output format: {} (expected utf8, lossy, latin1, hex or base64)", other)),
+    }
+}
+
+/// Lossy UTF-8 with control characters escaped, so a candidate fits on one output line.
+pub fn escape_controls(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .chars()
+        .map(|c| if c.is_control() { c.escape_default().to_string() } else { c.to_string() })
+        .collect()
+}
+
+pub fn hexdump(input: &str, width: usize, group: usize, uppercase: bool, start_offset: u64) -> Result<String> {
+    if width == 0 {
+        return Err(anyhow!("Width must be at least 1"));
+    }
+
+    let bytes = input.as_bytes();
+    let group = if group == 0 { width } else { group.min(width) };
+    let groups_per_line = width.div_ceil(group);
+    let hex_width = width * 2 + groups_per_line - 1;
+
+    let mut lines = Vec::new();
+    for (i, chunk) in bytes.chunks(width).enumerate() {
+        let offset = start_offset
+            .checked_add((i * width) as u64)
+            .ok_or_else(|| anyhow!("Start offset 0x{:x} overflows for this input length", start_offset))?;
+        let hex: Vec<String> = chunk
+            .chunks(group)
+            .map(|g| {
+                let encoded = hex::encode(g);
+                if uppercase { encoded.to_uppercase() } else { encoded }
+            })
+            .collect();
+        let ascii: String = chunk
+            .iter()
+            .map(|&b| if (0x20..=0x7e).contains(&b) { b as char } else { '.' })
+            .collect();
+        let offset = if uppercase { format!("{:08X}", offset) } else { format!("{:08x}", offset) };
+        lines.push(format!("{}: {:<hex_width$}  {}", offset, hex.join(" "), ascii));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+const MAX_HEXDUMP_BYTES: usize = 64 * 1024 * 1024;
+
+fn is_hex_token(token: &str) -> bool {
+    !token.is_empty() && token.len().is_multiple_of(2) && token.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn line_offset(line: &str) -> Option<u64> {
+    let token = line.split_whitespace().next()?;
+    u64::from_str_radix(token.trim_end_matches(':'), 16).ok()
+}
+
+/// Bytes per line, from the first two consecutive offset lines not separated by a `*`.
+fn hexdump_width(lines: &[&str]) -> Option<u64> {
+    lines.windows(2).find_map(|pair| {
+        if pair.iter().any(|line| line.trim_end() == "*") {
+            return None;
+        }
+        line_offset(pair[1])?.checked_sub(line_offset(pair[0])?).filter(|&width| width > 0)
+    })
+}
+
+/// Whether `rest` ends with an ASCII column for `count` bytes: the last `count` characters,
+/// separated from the hex by at least two spaces. Only single-byte dumps (`hexdump -C`,
+/// Wireshark) put two spaces inside the data, so without padding a column of single-byte
+/// tokens on such a line is taken as data.
+fn is_ascii_column(rest: &str, count: usize, byte_groups: bool) -> bool {
+    let chars = rest.chars().count();
+    if chars < count + 2 {
+        return false;
+    }
+    let split = rest.char_indices().nth(chars - count).map_or(rest.len(), |(i, _)| i);
+    let (gap, column) = rest.split_at(split);
+    if !gap.chars().all(char::is_whitespace) {
+        return false;
+    }
+    gap.chars().count() > 2 || !byte_groups || !column.split_whitespace().all(|token| token.len() == 2 && is_hex_token(token))
+}
+
+/// Parse `xxd`, `hexdump -C` and Wireshark hex dumps back into bytes.
+///
+/// Offsets and the ASCII column are discarded; a `*` line (squeezed repeats in
+/// `hexdump`) is expanded using the offset of the following line.
+pub fn from_hexdump_bytes(input: &str) -> Result<Vec<u8>> {
+    // Trailing whitespace is kept, since it can be part of the ASCII column
+    let lines: Vec<&str> = input.lines().map(|l| l.trim_start()).filter(|l| !l.trim_end().is_empty()).collect();
+
+    let has_offsets = lines.first().is_some_and(|line| {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        tokens[0].ends_with(':') || (tokens.len() > 1 && tokens[0].len() >= 4 && tokens[0].len() > tokens[1].len())
+    });
+    let width = if has_offsets { hexdump_width(&lines) } else { None };
+
+    let mut bytes = Vec::new();
+    let mut previous_line: Vec<u8> = Vec::new();
+    let mut pending_repeat = false;
+    // Offsets are taken relative to the first line, so dumps of a slice of a larger file work
+    let mut first_offset: Option<u64> = None;
+    let mut last_offset = 0;
+
+    for line in lines {
+        if line.trim_end() == "*" {
+            pending_repeat = true;
+            continue;
+        }
+
+        // hexdump -C wraps the ASCII column in pipes
+        let line = match line.find('|') {
+            Some(pos) => line[..pos].trim_end(),
+            None => line,
+        };
+
+        let mut data = line;
+        if has_offsets {
+            let (offset, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let offset = u64::from_str_radix(offset.trim_end_matches(':'), 16)
+                .map_err(|_| anyhow!("Invalid offset in hexdump line: {}", line.trim_end()))?;
+
+            if offset < last_offset {
+                return Err(anyhow!("Hexdump offset goes backwards: {}", line.trim_end()));
+            }
+            last_offset = offset;
+            let position = offset - *first_offset.get_or_insert(offset);
+
+            if pending_repeat && !previous_line.is_empty() {
+                if position > MAX_HEXDUMP_BYTES as u64 {
+                    return Err(anyhow!("Hexdump expands to more than {} bytes", MAX_HEXDUMP_BYTES));
+                }
+                while bytes.len() < position as usize {
+                    bytes.extend_from_slice(&previous_line);
+                }
+                bytes.truncate(position as usize);
+            }
+            pending_repeat = false;
+            data = rest.trim_start();
+        }
+
+        // Segments are separated by runs of two or more spaces. Collection stops at the line
+        // width when offsets give one, at an ASCII column, or at the first segment that isn't
+        // all hex tokens.
+        let mut line_bytes = Vec::new();
+        let byte_groups = data.split_whitespace().next().is_some_and(|token| token.len() == 2);
+        let mut rest = data;
+        loop {
+            let trimmed = rest.trim_start();
+            if trimmed.is_empty() || width.is_some_and(|width| line_bytes.len() as u64 >= width) {
+                break;
+            }
+            if !line_bytes.is_empty() && is_ascii_column(rest, line_bytes.len(), byte_groups) {
+                break;
+            }
+            let end = trimmed.find("  ").unwrap_or(trimmed.len());
+            let segment = &trimmed[..end];
+            if !segment.split_whitespace().all(is_hex_token) {
+                break;
+            }
+            for token in segment.split_whitespace() {
+                line_bytes.extend(hex::decode(token)?);
+            }
+            rest = &trimmed[end..];
+        }
+
+        bytes.extend_from_slice(&line_bytes);
+        if !line_bytes.is_empty() {
+            previous_line = line_bytes;
+        }
+    }
+
+    Ok(bytes)
+}
+
+pub fn from_hexdump(input: &str, output_format: &str) -> Result<String> {
+    format_bytes(&from_hexdump_bytes(input)?, output_format)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,11 +439,55 @@ mod tests {
     #[test]
     fn test_url_encode_decode() {
         let input = "Hello World & Friends";
-        let encoded = url_encode(input).unwrap();
-        let decoded = url_decode(&encoded).unwrap();
+        let encoded = url_encode(input, "component").unwrap();
+        let decoded = url_decode(&encoded, false, false).unwrap();
         assert_eq!(input, decoded);
     }
 
+    #[test]
+    fn test_url_encode_sets() {
+        let input = "a b&c=d/e?f#g";
+        assert_eq!(url_encode(input, "component").unwrap(), "a%20b%26c%3Dd%2Fe%3Ff%23g");
+        assert_eq!(url_encode(input, "path").unwrap(), "a%20b&c=d/e%3Ff%23g");
+        assert_eq!(url_encode(input, "query").unwrap(), "a%20b&c=d/e?f%23g");
+        assert_eq!(url_encode(input, "form").unwrap(), "a+b%26c%3Dd%2Fe%3Ff%23g");
+        assert_eq!(url_encode("ab", "all").unwrap(), "%61%62");
+    }
+
+    #[test]
+    fn test_url_decode_recursive() {
+        let input = "%253Cscript%253E";
+        assert_eq!(url_decode(input, false, false).unwrap(), "%3Cscript%3E");
+        assert_eq!(url_decode(input, false, true).unwrap(), "<script>");
+        assert_eq!(url_decode("a+b%2Bc", true, false).unwrap(), "a b+c");
+        assert_eq!(url_decode("a+b%2Bc", true, true).unwrap(), "a b+c");
+        assert_eq!(url_decode("a%252B+b", true, true).unwrap(), "a+ b");
+    }
+
+    #[test]
+    fn test_html_encode_modes() {
+        assert_eq!(html_encode("<a href=\"x\">café</a>", "minimal").unwrap(), "&lt;a href=&quot;x&quot;&gt;café&lt;/a&gt;");
+        assert_eq!(html_encode("café €", "non_ascii").unwrap(), "caf&#xe9; &#x20ac;");
+        assert_eq!(html_encode("a&é€", "all").unwrap(), "&#x61;&amp;&eacute;&euro;");
+    }
+
+    #[test]
+    fn test_html_decode() {
+        assert_eq!(html_decode("&amp;lt;").unwrap(), "&lt;");
+        assert_eq!(html_decode("&lt;b&gt; &eacute;&hellip; &#65;&#x42;&#X43; &#128;").unwrap(), "<b> é… ABC €");
+        assert_eq!(html_decode("&copy 2024 &notit; &bogus; & &#;").unwrap(), "© 2024 ¬it; &bogus; & &#;");
+        assert_eq!(html_decode("&CounterClockwiseContourIntegral;&#0;&#x110000;").unwrap(), "∳\u{fffd}\u{fffd}");
+    }
+
+    #[test]
+    fn test_html_roundtrip() {
+        let input = "<script>alert('x & y')</script> naïve ∑";
+        for mode in ["minimal", "non_ascii", "all"] {
+            let encoded = html_encode(input, mode).unwrap();
+            assert_eq!(html_decode(&encoded).unwrap(), input);
+        }
+    }
+
     #[test]
     fn test_hex_encode_decode() {
         let input = "Hello, World!";
@@ -82,4 +495,73 @@ mod tests {
         let decoded = hex_decode(&encoded).unwrap();
         assert_eq!(input, decoded);
     }
+
+    #[test]
+    fn test_hex_decode_delimiters() {
+        assert_eq!(hex_decode("48 65:6c,6c 6f").unwrap(), "Hello");
+        assert_eq!(hex_decode("\\x48\\x65\\x6c\\x6c\\x6f").unwrap(), "Hello");
+        assert_eq!(hex_decode("0x48 0x65 0x6C 0x6C 0x6F").unwrap(), "Hello");
+    }
+
+    #[test]
+    fn test_base32_decode() {
+        // RFC 4648 test vectors
+        assert_eq!(base32_decode_bytes("MZXW6YQ=").unwrap(), b"foob");
+        assert_eq!(base32_decode_bytes("MZXW6YTBOI======").unwrap(), b"foobar");
+        assert_eq!(base32_decode_bytes("mzxw 6ytb-oi").unwrap(), b"foobar");
+        assert!(base32_decode_bytes("MZXW1").is_err());
+    }
+
+    #[test]
+    fn test_hexdump() {
+        let result = hexdump("Hello, World!\n", 16, 2, false, 0).unwrap();
+        assert_eq!(result, "00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a       Hello, World!.");
+
+        let input = "A".repeat(40);
+        assert!(hexdump(&input, 16, 2, false, u64::MAX - 1).is_err());
+        assert!(hexdump("A", 16, 2, false, u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_from_hexdump_formats() {
+        let xxd = "00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a       Hello, World!.";
+        let hexdump_c = "00000000  48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 0a        |Hello, World!.|\n0000000e";
+        let wireshark = "0000   48 65 6c 6c 6f 2c 20 57 6f 72 6c 64 21 0a         Hello, World!.";
+        for dump in [xxd, hexdump_c, wireshark] {
+            assert_eq!(from_hexdump(dump, "utf8").unwrap(), "Hello, World!\n");
+        }
+    }
+
+    #[test]
+    fn test_from_hexdump_roundtrip_and_repeat() {
+        let input = "The quick brown fox jumps over the lazy dog";
+        let dump = hexdump(input, 8, 1, true, 0).unwrap();
+        assert_eq!(from_hexdump(&dump, "utf8").unwrap(), input);
+
+        let squeezed = "00000000  41 41 41 41 41 41 41 41  41 41 41 41 41 41 41 41  |AAAAAAAAAAAAAAAA|\n*\n00000030  42 0a                                             |B.|\n00000032";
+        let expected = format!("{}B\n", "A".repeat(48));
+        assert_eq!(from_hexdump(squeezed, "utf8").unwrap(), expected);
+
+        let shifted = squeezed.replace("000000", "000010");
+        assert_eq!(from_hexdump(&shifted, "utf8").unwrap(), expected);
+        let huge = squeezed.replace("00000030", "ffffffffffff");
+        assert!(from_hexdump(&huge, "utf8").is_err());
+        assert!(from_hexdump("00000010  41\n00000000  42", "utf8").is_err());
+    }
+
+    #[test]
+    fn test_from_hexdump_without_ascii_column() {
+        let line = "00000000  41 41 41 41 41 41 41 41  42 42 42";
+        assert_eq!(from_hexdump(line, "utf8").unwrap(), "AAAAAAAABBB");
+    }
+
+    #[test]
+    fn test_from_hexdump_roundtrip_hex_looking_text() {
+        for input in ["cafe", "ab", "12345678", "0123456789abcdef", "0123456789abcdef0123", "ab cd ef ", " 00 11"] {
+            for (width, group) in [(16, 2), (16, 1), (8, 4), (16, 0)] {
+                let dump = hexdump(input, width, group, false, 0).unwrap();
+                assert_eq!(from_hexdump(&dump, "utf8").unwrap(), input, "{}", dump);
+            }
+        }
+    }
 }
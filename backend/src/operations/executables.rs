@@ -0,0 +1,593 @@
+use anyhow::{anyhow, Result};
+use goblin::elf::{self, Elf};
+use goblin::pe::{self, section_table::SectionTable, PE};
+use md5::{Digest, Md5};
+use serde_json::{json, Value};
+
+use super::analysis::shannon_entropy;
+use super::encoding::parse_bytes;
+
+const MAX_SYMBOLS: usize = 5000;
+
+fn hex(value: u64) -> String {
+    format!("0x{:x}", value)
+}
+
+fn entropy_of(data: &[u8], offset: usize, size: usize) -> Option<f64> {
+    let bytes = data.get(offset..offset.checked_add(size)?)?;
+    Some((shannon_entropy(bytes) * 10_000.0).round() / 10_000.0)
+}
+
+fn permissions(read: bool, write: bool, execute: bool) -> String {
+    [(read, 'r'), (write, 'w'), (execute, 'x')].iter().map(|&(set, c)| if set { c } else { '-' }).collect()
+}
+
+// Section headers are untrusted, so the arithmetic is done in u64 where u32 fields can't overflow
+fn rva_to_offset(sections: &[SectionTable], rva: u32) -> Option<usize> {
+    let rva = rva as u64;
+    sections.iter().find_map(|s| {
+        let start = s.virtual_address as u64;
+        let end = start + s.virtual_size.max(s.size_of_raw_data) as u64;
+        (start..end).contains(&rva).then(|| usize::try_from(rva - start + s.pointer_to_raw_data as u64).ok())?
+    })
+}
+
+fn subsystem_name(subsystem: u16) -> String {
+    let name = match subsystem {
+        1 => "native",
+        2 => "Windows GUI",
+        3 => "Windows console",
+        5 => "OS/2 console",
+        7 => "POSIX console",
+        9 => "Windows CE GUI",
+        10 => "EFI application",
+        11 => "EFI boot service driver",
+        12 => "EFI runtime driver",
+        13 => "EFI ROM",
+        14 => "Xbox",
+        16 => "Windows boot application",
+        _ => return subsystem.to_string(),
+    };
+    name.to_string()
+}
+
+fn resource_type_name(id: u16) -> String {
+    let name = match id {
+        1 => "RT_CURSOR",
+        2 => "RT_BITMAP",
+        3 => "RT_ICON",
+        4 => "RT_MENU",
+        5 => "RT_DIALOG",
+        6 => "RT_STRING",
+        7 => "RT_FONTDIR",
+        8 => "RT_FONT",
+        9 => "RT_ACCELERATOR",
+        10 => "RT_RCDATA",
+        11 => "RT_MESSAGETABLE",
+        12 => "RT_GROUP_CURSOR",
+        14 => "RT_GROUP_ICON",
+        16 => "RT_VERSION",
+        17 => "RT_DLGINCLUDE",
+        19 => "RT_PLUGPLAY",
+        20 => "RT_VXD",
+        21 => "RT_ANICURSOR",
+        22 => "RT_ANIICON",
+        23 => "RT_HTML",
+        24 => "RT_MANIFEST",
+        _ => return id.to_string(),
+    };
+    name.to_string()
+}
+
+// Resource directory strings are a u16 length followed by that many UTF-16LE code units
+fn resource_string(data: &[u8], offset: usize) -> Option<String> {
+    let start = offset.checked_add(2)?;
+    let length = u16::from_le_bytes(data.get(offset..start)?.try_into().ok()?) as usize;
+    let units: Vec<u16> = data.get(start..start.checked_add(length * 2)?)?.chunks(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+    Some(String::from_utf16_lossy(&units))
+}
+
+// Top level of the resource tree: one entry per type, with the number of resources of that type
+fn pe_resources(pe: &PE, data: &[u8]) -> Vec<Value> {
+    let Some(resources) = &pe.resource_data else { return Vec::new() };
+    let root = pe
+        .header
+        .optional_header
+        .and_then(|header| header.data_directories.get_resource_table().copied())
+        .and_then(|directory| rva_to_offset(&pe.sections, directory.virtual_address));
+    let Some(root) = root else { return Vec::new() };
+    resources
+        .entries()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| {
+            let name = match entry.id() {
+                Some(id) => resource_type_name(id),
+                None => root.checked_add(entry.name_offset() as usize).and_then(|offset| resource_string(data, offset)).unwrap_or_default(),
+            };
+            let count = root
+                .checked_add(entry.offset_to_directory() as usize)
+                .and_then(|directory| data.get(directory.checked_add(12)?..directory.checked_add(16)?))
+                .filter(|_| entry.data_is_directory())
+                .map_or(1, |counts| u16::from_le_bytes([counts[0], counts[1]]) as usize + u16::from_le_bytes([counts[2], counts[3]]) as usize);
+            json!({ "type": name, "count": count })
+        })
+        .collect()
+}
+
+fn pe_version_info(pe: &PE) -> Option<Value> {
+    let strings = &pe.resource_data.as_ref()?.version_info.as_ref()?.string_info;
+    let fields = [
+        ("company_name", strings.company_name()),
+        ("file_description", strings.file_description()),
+        ("file_version", strings.file_version()),
+        ("internal_name", strings.internal_name()),
+        ("original_filename", strings.original_filename()),
+        ("product_name", strings.product_name()),
+        ("product_version", strings.product_version()),
+        ("legal_copyright", strings.legal_copyright()),
+    ];
+    let info: serde_json::Map<String, Value> =
+        fields.into_iter().filter_map(|(key, value)| Some((key.to_string(), Value::String(value?)))).collect();
+    (!info.is_empty()).then_some(Value::Object(info))
+}
+
+fn pe_rich_header(pe: &PE) -> Option<Value> {
+    let rich = pe.header.rich_header.as_ref()?;
+    let entries: Vec<Value> = rich
+        .metadatas()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| json!({ "product_id": entry.product, "build": entry.build, "count": entry.use_count }))
+        .collect();
+    Some(json!({ "key": format!("0x{:08x}", rich.key), "entries": entries }))
+}
+
+/// Import hash as computed by pefile: MD5 of the comma-joined, lowercased `library.function`
+/// pairs in import order, with .dll/.ocx/.sys dropped from library names. Imports by ordinal
+/// appear as `ordN`; pefile's name lookup for ws2_32 and oleaut32 ordinals is not replicated.
+pub fn imphash(imports: &[pe::import::Import]) -> Option<String> {
+    if imports.is_empty() {
+        return None;
+    }
+    let entries: Vec<String> = imports
+        .iter()
+        .map(|import| {
+            let dll = import.dll.to_lowercase();
+            let library = [".dll", ".ocx", ".sys"].iter().find_map(|ext| dll.strip_suffix(ext)).unwrap_or(&dll);
+            let function = match import.name.strip_prefix("ORDINAL ") {
+                Some(_) => format!("ord{}", import.ordinal),
+                None => import.name.to_lowercase(),
+            };
+            format!("{}.{}", library, function)
+        })
+        .collect();
+    Some(hex::encode(Md5::digest(entries.join(","))))
+}
+
+/// Headers, sections with entropy, imports, exports, imphash, resources, Rich header and
+/// Authenticode signature of a Windows PE file, as JSON.
+pub fn parse_pe(input: &str, input_format: &str) -> Result<String> {
+    let data = parse_bytes(input, input_format)?;
+    let pe = PE::parse(&data).map_err(|e| anyhow!("Not a valid PE file: {}", e))?;
+    let coff = &pe.header.coff_header;
+    let optional = pe.header.optional_header.as_ref();
+
+    let sections: Vec<Value> = pe
+        .sections
+        .iter()
+        .map(|section| {
+            let flags = section.characteristics;
+            json!({
+                "name": section.name().unwrap_or_default(),
+                "virtual_address": hex(section.virtual_address as u64),
+                "virtual_size": section.virtual_size,
+                "raw_offset": section.pointer_to_raw_data,
+                "raw_size": section.size_of_raw_data,
+                "permissions": permissions(
+                    flags & pe::section_table::IMAGE_SCN_MEM_READ != 0,
+                    flags & pe::section_table::IMAGE_SCN_MEM_WRITE != 0,
+                    flags & pe::section_table::IMAGE_SCN_MEM_EXECUTE != 0,
+                ),
+                "entropy": entropy_of(&data, section.pointer_to_raw_data as usize, section.size_of_raw_data as usize),
+            })
+        })
+        .collect();
+
+    let mut imports: Vec<Value> = Vec::new();
+    for import in &pe.imports {
+        let name = import.name.to_string();
+        match imports.last_mut() {
+            Some(last) if last["library"] == import.dll => last["functions"].as_array_mut().unwrap().push(name.into()),
+            _ => imports.push(json!({ "library": import.dll, "functions": [name] })),
+        }
+    }
+    let exports: Vec<Value> = pe
+        .exports
+        .iter()
+        .map(|export| json!({ "name": export.name, "rva": hex(export.rva as u64) }))
+        .collect();
+    let signatures: Vec<Value> = pe
+        .certificates
+        .iter()
+        .map(|certificate| json!({ "type": format!("{:?}", certificate.certificate_type), "length": certificate.length }))
+        .collect();
+
+    let result = json!({
+        "format": if pe.is_64 { "PE32+" } else { "PE32" },
+        "machine": pe::header::machine_to_str(coff.machine),
+        "timestamp": chrono::DateTime::from_timestamp(coff.time_date_stamp as i64, 0).map(|t| t.to_rfc3339()),
+        "type": if pe.is_lib { "DLL" } else { "executable" },
+        "subsystem": optional.map(|header| subsystem_name(header.windows_fields.subsystem)),
+        "image_base": hex(pe.image_base),
+        "entry_point": hex(pe.entry as u64),
+        "sections": sections,
+        "imports": imports,
+        "imphash": imphash(&pe.imports),
+        "exports": exports,
+        "resources": pe_resources(&pe, &data),
+        "version_info": pe_version_info(&pe),
+        "rich_header": pe_rich_header(&pe),
+        "authenticode": { "signed": !signatures.is_empty(), "certificates": signatures },
+    });
+    Ok(serde_json::to_string_pretty(&result)?)
+}
+
+fn elf_abi(osabi: u8) -> String {
+    let name = match osabi {
+        0 => "System V",
+        1 => "HP-UX",
+        2 => "NetBSD",
+        3 => "Linux",
+        6 => "Solaris",
+        7 => "AIX",
+        8 => "IRIX",
+        9 => "FreeBSD",
+        12 => "OpenBSD",
+        97 => "ARM",
+        255 => "Standalone",
+        _ => return osabi.to_string(),
+    };
+    name.to_string()
+}
+
+fn elf_symbols(symbols: &elf::Symtab, strings: &goblin::strtab::Strtab, table: &str) -> Vec<Value> {
+    symbols
+        .iter()
+        .filter_map(|symbol| {
+            let name = strings.get_at(symbol.st_name).filter(|name| !name.is_empty())?;
+            Some(json!({
+                "name": name,
+                "value": hex(symbol.st_value),
+                "size": symbol.st_size,
+                "type": elf::sym::type_to_str(symbol.st_type()),
+                "bind": elf::sym::bind_to_str(symbol.st_bind()),
+                "table": table,
+            }))
+        })
+        .collect()
+}
+
+/// Header, sections, segments, symbols and needed libraries of an ELF file, as JSON.
+pub fn parse_elf(input: &str, input_format: &str) -> Result<String> {
+    let data = parse_bytes(input, input_format)?;
+    let elf = Elf::parse(&data).map_err(|e| anyhow!("Not a valid ELF file: {}", e))?;
+    let header = &elf.header;
+
+    let sections: Vec<Value> = elf
+        .section_headers
+        .iter()
+        .skip(1)
+        .map(|section| {
+            let flags = section.sh_flags;
+            let mut flag_letters = String::new();
+            for (bit, letter) in [(elf::section_header::SHF_WRITE, 'W'), (elf::section_header::SHF_ALLOC, 'A'), (elf::section_header::SHF_EXECINSTR, 'X')] {
+                if flags & bit as u64 != 0 {
+                    flag_letters.push(letter);
+                }
+            }
+            let in_file = section.sh_type != elf::section_header::SHT_NOBITS;
+            json!({
+                "name": elf.shdr_strtab.get_at(section.sh_name).unwrap_or_default(),
+                "type": elf::section_header::sht_to_str(section.sh_type),
+                "flags": flag_letters,
+                "address": hex(section.sh_addr),
+                "offset": section.sh_offset,
+                "size": section.sh_size,
+                "entropy": if in_file { entropy_of(&data, section.sh_offset as usize, section.sh_size as usize) } else { None },
+            })
+        })
+        .collect();
+    let segments: Vec<Value> = elf
+        .program_headers
+        .iter()
+        .map(|segment| {
+            json!({
+                "type": elf::program_header::pt_to_str(segment.p_type),
+                "permissions": permissions(segment.is_read(), segment.is_write(), segment.is_executable()),
+                "offset": segment.p_offset,
+                "virtual_address": hex(segment.p_vaddr),
+                "file_size": segment.p_filesz,
+                "memory_size": segment.p_memsz,
+            })
+        })
+        .collect();
+    let mut symbols = elf_symbols(&elf.syms, &elf.strtab, "symtab");
+    symbols.extend(elf_symbols(&elf.dynsyms, &elf.dynstrtab, "dynsym"));
+    let symbol_count = symbols.len();
+    symbols.truncate(MAX_SYMBOLS);
+
+    let result = json!({
+        "class": if elf.is_64 { "ELF64" } else { "ELF32" },
+        "endianness": if elf.little_endian { "little" } else { "big" },
+        "abi": elf_abi(header.e_ident[elf::header::EI_OSABI]),
+        "type": elf::header::et_to_str(header.e_type),
+        "machine": elf::header::machine_to_str(header.e_machine),
+        "entry_point": hex(elf.entry),
+        "interpreter": elf.interpreter,
+        "soname": elf.soname,
+        "libraries": elf.libraries,
+        "sections": sections,
+        "segments": segments,
+        "symbol_count": symbol_count,
+        "symbols": symbols,
+    });
+    Ok(serde_json::to_string_pretty(&result)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+        data[offset..offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    // PE32 executable with a Rich header, an import table in .idata, a resource tree in .rsrc
+    // and an attribute certificate appended after the sections
+    fn pe_image() -> Vec<u8> {
+        let mut data = vec![0u8; 0x810];
+        put(&mut data, 0, b"MZ");
+        put(&mut data, 0x3c, &0x80u32.to_le_bytes());
+        // "DanS", three padding dwords and one (product, build) record, all XORed with the key
+        let key = 0x1234_5678u32;
+        let rich = [u32::from_le_bytes(*b"DanS"), 0, 0, 0, 0x0104 << 16 | 30729, 3];
+        for (i, dword) in rich.iter().enumerate() {
+            put(&mut data, 0x40 + i * 4, &(dword ^ key).to_le_bytes());
+        }
+        put(&mut data, 0x58, b"Rich");
+        put(&mut data, 0x5c, &key.to_le_bytes());
+
+        let pe = 0x80;
+        put(&mut data, pe, b"PE\0\0");
+        put(&mut data, pe + 4, &0x14cu16.to_le_bytes());
+        put(&mut data, pe + 6, &3u16.to_le_bytes());
+        put(&mut data, pe + 8, &1_600_000_000u32.to_le_bytes());
+        put(&mut data, pe + 20, &0xe0u16.to_le_bytes());
+        put(&mut data, pe + 22, &0x0102u16.to_le_bytes());
+        let optional = pe + 24;
+        put(&mut data, optional, &0x10bu16.to_le_bytes());
+        put(&mut data, optional + 16, &0x1000u32.to_le_bytes());
+        put(&mut data, optional + 28, &0x400000u32.to_le_bytes());
+        put(&mut data, optional + 32, &0x1000u32.to_le_bytes());
+        put(&mut data, optional + 36, &0x200u32.to_le_bytes());
+        put(&mut data, optional + 56, &0x4000u32.to_le_bytes());
+        put(&mut data, optional + 60, &0x200u32.to_le_bytes());
+        put(&mut data, optional + 68, &3u16.to_le_bytes());
+        put(&mut data, optional + 92, &16u32.to_le_bytes());
+        // Import, resource and security directories; the last is addressed by file offset
+        for (index, address, size) in [(1, 0x2000u32, 0x28u32), (2, 0x3000, 0x100), (4, 0x800, 0x10)] {
+            put(&mut data, optional + 96 + index * 8, &address.to_le_bytes());
+            put(&mut data, optional + 100 + index * 8, &size.to_le_bytes());
+        }
+        let sections = [
+            (b".text\0\0\0", 0x1000u32, 0x200u32, 0x6000_0020u32),
+            (b".idata\0\0", 0x2000, 0x400, 0xc000_0040),
+            (b".rsrc\0\0\0", 0x3000, 0x600, 0x4000_0040),
+        ];
+        for (i, (name, address, raw_offset, flags)) in sections.iter().enumerate() {
+            let header = optional + 0xe0 + i * 40;
+            put(&mut data, header, *name);
+            for (field, value) in [(8, 0x100u32), (12, *address), (16, 0x200), (20, *raw_offset), (36, *flags)] {
+                put(&mut data, header + field, &value.to_le_bytes());
+            }
+        }
+
+        // KERNEL32.dll: GetProcAddress by name, then ordinal 17
+        let idata = 0x400;
+        for (field, value) in [(0, 0x2040u32), (12, 0x2080), (16, 0x2060)] {
+            put(&mut data, idata + field, &value.to_le_bytes());
+        }
+        for thunks in [0x40, 0x60] {
+            put(&mut data, idata + thunks, &0x20a0u32.to_le_bytes());
+            put(&mut data, idata + thunks + 4, &0x8000_0011u32.to_le_bytes());
+        }
+        put(&mut data, idata + 0x80, b"KERNEL32.dll\0");
+        put(&mut data, idata + 0xa2, b"GetProcAddress\0");
+
+        // Resource types "CONFIG" (by name, one resource) and RT_RCDATA (by ID, two resources)
+        let rsrc = 0x600;
+        let entries = [
+            (0x0c, 0x0001_0001u32),
+            (0x10, 0x8000_00c0),
+            (0x14, 0x8000_0020),
+            (0x18, 10),
+            (0x1c, 0x8000_0038),
+            (0x2c, 0x0001_0000),
+            (0x30, 1),
+            (0x34, 0x90),
+            (0x44, 0x0002_0000),
+            (0x48, 1),
+            (0x4c, 0x90),
+            (0x50, 2),
+            (0x54, 0xa0),
+            (0x90, 0x30b0),
+            (0x94, 4),
+            (0xa0, 0x30b0),
+            (0xa4, 4),
+        ];
+        for (offset, value) in entries {
+            put(&mut data, rsrc + offset, &value.to_le_bytes());
+        }
+        put(&mut data, rsrc + 0xb0, b"data");
+        put(&mut data, rsrc + 0xc0, &6u16.to_le_bytes());
+        put(&mut data, rsrc + 0xc2, &"CONFIG".encode_utf16().flat_map(u16::to_le_bytes).collect::<Vec<u8>>());
+
+        // WIN_CERTIFICATE: length, revision 2.0, PKCS#7 signed data
+        put(&mut data, 0x800, &[0x10, 0, 0, 0, 0, 2, 2, 0]);
+        data
+    }
+
+    #[test]
+    fn test_parse_pe() {
+        let result: Value = serde_json::from_str(&parse_pe(&hex::encode(pe_image()), "hex").unwrap()).unwrap();
+        assert_eq!(result["format"], "PE32");
+        assert_eq!(result["machine"], "X86");
+        assert_eq!(result["timestamp"], "2020-09-13T12:26:40+00:00");
+        assert_eq!(result["entry_point"], "0x1000");
+        assert_eq!(result["subsystem"], "Windows console");
+        assert_eq!(result["sections"][0]["name"], ".text");
+        assert_eq!(result["sections"][0]["permissions"], "r-x");
+        assert_eq!(result["sections"][0]["entropy"], 0.0);
+        assert_eq!(result["sections"][1]["permissions"], "rw-");
+        assert!(parse_pe("MZ not really", "latin1").is_err());
+    }
+
+    #[test]
+    fn test_parse_pe_tables() {
+        let result: Value = serde_json::from_str(&parse_pe(&hex::encode(pe_image()), "hex").unwrap()).unwrap();
+        assert_eq!(result["imports"], json!([{ "library": "KERNEL32.dll", "functions": ["GetProcAddress", "ORDINAL 17"] }]));
+        let expected_imphash = hex::encode(Md5::digest("kernel32.getprocaddress,kernel32.ord17"));
+        assert_eq!(result["imphash"], expected_imphash);
+        assert_eq!(result["resources"], json!([{ "type": "CONFIG", "count": 1 }, { "type": "RT_RCDATA", "count": 2 }]));
+        assert_eq!(result["rich_header"], json!({ "key": "0x12345678", "entries": [{ "product_id": 260, "build": 30729, "count": 3 }] }));
+        assert_eq!(result["authenticode"], json!({ "signed": true, "certificates": [{ "type": "PkcsSignedData", "length": 16 }] }));
+    }
+
+    #[test]
+    fn test_rva_to_offset_near_address_space_end() {
+        let sections = [SectionTable { virtual_address: 0xffff_f000, virtual_size: 0x2000, pointer_to_raw_data: 0xffff_ff00, ..Default::default() }];
+        assert_eq!(rva_to_offset(&sections, 0xffff_f010), Some(0xffff_ff10));
+        assert_eq!(rva_to_offset(&sections, 0x1000), None);
+    }
+
+    #[test]
+    fn test_imphash() {
+        let import = |dll, name: &'static str, ordinal| pe::import::Import { name: Cow::Borrowed(name), dll, ordinal, offset: 0, rva: 0, size: 0 };
+        let imports = [
+            import("KERNEL32.dll", "GetProcAddress", 0),
+            import("KERNEL32.dll", "LoadLibraryA", 0),
+            import("COMCTL32.dll", "ORDINAL 17", 17),
+        ];
+        assert_eq!(imphash(&imports).unwrap(), "f27781d485fb284685652745f785f57f");
+        assert_eq!(imphash(&[]), None);
+    }
+
+    #[test]
+    fn test_parse_elf() {
+        // ELF64 executable header followed by one read/execute PT_LOAD segment covering the file
+        let mut data = vec![0u8; 120];
+        put(&mut data, 0, b"\x7fELF\x02\x01\x01\x03");
+        put(&mut data, 16, &2u16.to_le_bytes());
+        put(&mut data, 18, &62u16.to_le_bytes());
+        put(&mut data, 20, &1u32.to_le_bytes());
+        put(&mut data, 24, &0x401000u64.to_le_bytes());
+        put(&mut data, 32, &64u64.to_le_bytes());
+        put(&mut data, 52, &[64, 0, 56, 0, 1, 0, 64, 0]);
+        put(&mut data, 64, &1u32.to_le_bytes());
+        put(&mut data, 68, &5u32.to_le_bytes());
+        put(&mut data, 80, &0x400000u64.to_le_bytes());
+        put(&mut data, 96, &120u64.to_le_bytes());
+        put(&mut data, 104, &120u64.to_le_bytes());
+        let result: Value = serde_json::from_str(&parse_elf(&hex::encode(&data), "hex").unwrap()).unwrap();
+        assert_eq!(result["class"], "ELF64");
+        assert_eq!(result["abi"], "Linux");
+        assert_eq!(result["type"], "EXEC");
+        assert_eq!(result["machine"], "X86_64");
+        assert_eq!(result["entry_point"], "0x401000");
+        assert_eq!(result["segments"][0], json!({
+            "type": "PT_LOAD", "permissions": "r-x", "offset": 0, "virtual_address": "0x400000", "file_size": 120, "memory_size": 120,
+        }));
+        assert_eq!(result["libraries"], json!([]));
+        assert!(parse_elf("not an elf", "utf8").is_err());
+    }
+
+    // ELF64 shared object: PT_DYNAMIC needs libc.so.6 and exports `puts` (DT_HASH gives the
+    // dynamic symbol count), a .symtab holds `main`, and vaddr equals file offset throughout
+    fn elf_image() -> Vec<u8> {
+        let mut data = vec![0u8; 0x3e0];
+        put(&mut data, 0, b"\x7fELF\x02\x01\x01\x00");
+        put(&mut data, 16, &3u16.to_le_bytes());
+        put(&mut data, 18, &62u16.to_le_bytes());
+        put(&mut data, 20, &1u32.to_le_bytes());
+        put(&mut data, 32, &64u64.to_le_bytes());
+        put(&mut data, 40, &0x1e0u64.to_le_bytes());
+        put(&mut data, 52, &[64, 0, 56, 0, 2, 0, 64, 0, 8, 0, 7, 0]);
+        // (type, flags, offset and vaddr, size)
+        for (i, (p_type, flags, offset, size)) in [(1u32, 4u32, 0u64, 0x3e0u64), (2, 6, 0x100, 0x70)].into_iter().enumerate() {
+            let header = 64 + i * 56;
+            put(&mut data, header, &p_type.to_le_bytes());
+            put(&mut data, header + 4, &flags.to_le_bytes());
+            for field in [8, 16, 24] {
+                put(&mut data, header + field, &offset.to_le_bytes());
+            }
+            put(&mut data, header + 32, &size.to_le_bytes());
+            put(&mut data, header + 40, &size.to_le_bytes());
+        }
+        put(&mut data, 0xb0, b"\0libc.so.6\0puts\0");
+        put(&mut data, 0xd8, &[11, 0, 0, 0, 0x12]);
+        put(&mut data, 0xf0, &[1, 0, 0, 0, 2, 0, 0, 0, 1]);
+        // DT_NEEDED, DT_STRTAB, DT_STRSZ, DT_SYMTAB, DT_SYMENT, DT_HASH, DT_NULL
+        for (i, (tag, value)) in [(1u64, 1u64), (5, 0xb0), (10, 16), (6, 0xc0), (11, 24), (4, 0xf0), (0, 0)].into_iter().enumerate() {
+            put(&mut data, 0x100 + i * 16, &tag.to_le_bytes());
+            put(&mut data, 0x108 + i * 16, &value.to_le_bytes());
+        }
+        put(&mut data, 0x188, &[1, 0, 0, 0, 0x12, 0, 0xf1, 0xff]);
+        put(&mut data, 0x190, &0x1000u64.to_le_bytes());
+        put(&mut data, 0x198, &42u64.to_le_bytes());
+        put(&mut data, 0x1a0, b"\0main\0");
+        let names = "\0.dynstr\0.dynsym\0.hash\0.dynamic\0.symtab\0.strtab\0.shstrtab\0";
+        put(&mut data, 0x1a6, names.as_bytes());
+        // (name, type, flags, offset, size, link, entsize); allocated sections get sh_addr = offset
+        let sections = [
+            (".dynstr", 3u32, 2u64, 0xb0u64, 16u64, 0u32, 0u64),
+            (".dynsym", 11, 2, 0xc0, 48, 1, 24),
+            (".hash", 5, 2, 0xf0, 16, 2, 4),
+            (".dynamic", 6, 3, 0x100, 0x70, 1, 16),
+            (".symtab", 2, 0, 0x170, 48, 6, 24),
+            (".strtab", 3, 0, 0x1a0, 6, 0, 0),
+            (".shstrtab", 3, 0, 0x1a6, names.len() as u64, 0, 0),
+        ];
+        for (i, (name, sh_type, flags, offset, size, link, entsize)) in sections.into_iter().enumerate() {
+            let header = 0x1e0 + (i + 1) * 64;
+            put(&mut data, header, &(names.find(&format!("{}\0", name)).unwrap() as u32).to_le_bytes());
+            put(&mut data, header + 4, &sh_type.to_le_bytes());
+            put(&mut data, header + 8, &flags.to_le_bytes());
+            if flags != 0 {
+                put(&mut data, header + 16, &offset.to_le_bytes());
+            }
+            put(&mut data, header + 24, &offset.to_le_bytes());
+            put(&mut data, header + 32, &size.to_le_bytes());
+            put(&mut data, header + 40, &link.to_le_bytes());
+            put(&mut data, header + 56, &entsize.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_parse_elf_symbols_and_libraries() {
+        let result: Value = serde_json::from_str(&parse_elf(&hex::encode(elf_image()), "hex").unwrap()).unwrap();
+        assert_eq!(result["type"], "DYN");
+        assert_eq!(result["abi"], "System V");
+        assert_eq!(result["libraries"], json!(["libc.so.6"]));
+        let names: Vec<&str> = result["sections"].as_array().unwrap().iter().map(|s| s["name"].as_str().unwrap()).collect();
+        assert_eq!(names, [".dynstr", ".dynsym", ".hash", ".dynamic", ".symtab", ".strtab", ".shstrtab"]);
+        assert_eq!(result["sections"][1]["type"], "SHT_DYNSYM");
+        assert_eq!(result["sections"][3]["flags"], "WA");
+        assert_eq!(result["segments"][1]["type"], "PT_DYNAMIC");
+        assert_eq!(result["symbol_count"], 2);
+        assert_eq!(result["symbols"], json!([
+            { "name": "main", "value": "0x1000", "size": 42, "type": "FUNC", "bind": "GLOBAL", "table": "symtab" },
+            { "name": "puts", "value": "0x0", "size": 0, "type": "FUNC", "bind": "GLOBAL", "table": "dynsym" },
+        ]));
+    }
+}
@@ -0,0 +1,689 @@
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+
+use super::encoding::{format_bytes, parse_bytes};
+
+const MAX_SCAN_MATCHES: usize = 1000;
+const MAX_CARVED_FILES: usize = 256;
+const MAX_CARVED_BYTES: usize = 64 * 1024 * 1024;
+const MAX_PCAP_RECORD: u64 = 256 * 1024;
+// How far into an archive to look for the member names that identify OOXML, JAR and friends
+const ZIP_PROBE_LENGTH: usize = 64 * 1024;
+
+/// A magic-byte signature. `offset` is where `magic` sits relative to the start of the file, and
+/// `check` rejects coincidental matches for short or shared magics.
+pub struct FileSignature {
+    pub name: &'static str,
+    pub extension: &'static str,
+    pub mime: &'static str,
+    pub magic: &'static [u8],
+    pub offset: usize,
+    pub check: Option<fn(&[u8]) -> bool>,
+}
+
+const fn file_signature(name: &'static str, extension: &'static str, mime: &'static str, magic: &'static [u8]) -> FileSignature {
+    FileSignature { name, extension, mime, magic, offset: 0, check: None }
+}
+
+const fn checked(signature: FileSignature, check: fn(&[u8]) -> bool) -> FileSignature {
+    FileSignature { check: Some(check), ..signature }
+}
+
+const fn at_offset(signature: FileSignature, offset: usize) -> FileSignature {
+    FileSignature { offset, ..signature }
+}
+
+// Signatures sharing a magic are tried in order and only the first that passes is reported,
+// so the more specific variants come first.
+pub const FILE_SIGNATURES: &[FileSignature] = &[
+    checked(file_signature("Windows DLL", "dll", "application/vnd.microsoft.portable-executable", b"MZ"), is_pe_dll),
+    checked(file_signature("Windows PE executable", "exe", "application/vnd.microsoft.portable-executable", b"MZ"), is_pe),
+    file_signature("ELF executable", "elf", "application/x-elf", b"\x7fELF"),
+    file_signature("Mach-O executable (32-bit)", "macho", "application/x-mach-binary", b"\xfe\xed\xfa\xce"),
+    file_signature("Mach-O executable (64-bit)", "macho", "application/x-mach-binary", b"\xfe\xed\xfa\xcf"),
+    file_signature("Mach-O executable (32-bit)", "macho", "application/x-mach-binary", b"\xce\xfa\xed\xfe"),
+    file_signature("Mach-O executable (64-bit)", "macho", "application/x-mach-binary", b"\xcf\xfa\xed\xfe"),
+    checked(file_signature("Java class file", "class", "application/java-vm", b"\xca\xfe\xba\xbe"), is_java_class),
+    checked(file_signature("Mach-O universal binary", "macho", "application/x-mach-binary", b"\xca\xfe\xba\xbe"), is_fat_mach_o),
+    file_signature("WebAssembly module", "wasm", "application/wasm", b"\0asm"),
+    file_signature("PDF document", "pdf", "application/pdf", b"%PDF-"),
+    checked(
+        file_signature("Word document (OOXML)", "docx", "application/vnd.openxmlformats-officedocument.wordprocessingml.document", b"PK\x03\x04"),
+        |data| zip_has_member(data, b"word/"),
+    ),
+    checked(
+        file_signature("Excel workbook (OOXML)", "xlsx", "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet", b"PK\x03\x04"),
+        |data| zip_has_member(data, b"xl/"),
+    ),
+    checked(
+        file_signature("PowerPoint presentation (OOXML)", "pptx", "application/vnd.openxmlformats-officedocument.presentationml.presentation", b"PK\x03\x04"),
+        |data| zip_has_member(data, b"ppt/"),
+    ),
+    checked(file_signature("Android package", "apk", "application/vnd.android.package-archive", b"PK\x03\x04"), |data| {
+        zip_has_member(data, b"AndroidManifest.xml")
+    }),
+    checked(file_signature("Java archive", "jar", "application/java-archive", b"PK\x03\x04"), |data| zip_has_member(data, b"META-INF/MANIFEST.MF")),
+    file_signature("ZIP archive", "zip", "application/zip", b"PK\x03\x04"),
+    // An end-of-central-directory record on its own; in a real archive it lists the entries
+    checked(file_signature("ZIP archive (empty)", "zip", "application/zip", b"PK\x05\x06"), |data| u16_le(data, 10) == Some(0)),
+    file_signature("gzip compressed data", "gz", "application/gzip", b"\x1f\x8b\x08"),
+    checked(file_signature("bzip2 compressed data", "bz2", "application/x-bzip2", b"BZh"), is_bzip2),
+    file_signature("xz compressed data", "xz", "application/x-xz", b"\xfd7zXZ\0"),
+    file_signature("Zstandard compressed data", "zst", "application/zstd", b"\x28\xb5\x2f\xfd"),
+    file_signature("7-Zip archive", "7z", "application/x-7z-compressed", b"7z\xbc\xaf\x27\x1c"),
+    file_signature("RAR archive", "rar", "application/vnd.rar", b"Rar!\x1a\x07"),
+    at_offset(file_signature("tar archive", "tar", "application/x-tar", b"ustar"), 257),
+    at_offset(file_signature("ISO 9660 disk image", "iso", "application/x-iso9660-image", b"CD001"), 0x8001),
+    file_signature("Microsoft compound document (OLE2)", "doc", "application/x-ole-storage", b"\xd0\xcf\x11\xe0\xa1\xb1\x1a\xe1"),
+    file_signature("Rich Text Format document", "rtf", "application/rtf", b"{\\rtf"),
+    file_signature("PNG image", "png", "image/png", b"\x89PNG\r\n\x1a\n"),
+    file_signature("JPEG image", "jpg", "image/jpeg", b"\xff\xd8\xff"),
+    file_signature("GIF image", "gif", "image/gif", b"GIF87a"),
+    file_signature("GIF image", "gif", "image/gif", b"GIF89a"),
+    checked(file_signature("BMP image", "bmp", "image/bmp", b"BM"), is_bmp),
+    file_signature("TIFF image", "tif", "image/tiff", b"II*\0"),
+    file_signature("TIFF image", "tif", "image/tiff", b"MM\0*"),
+    file_signature("PCAP capture (little-endian)", "pcap", "application/vnd.tcpdump.pcap", b"\xd4\xc3\xb2\xa1"),
+    file_signature("PCAP capture (big-endian)", "pcap", "application/vnd.tcpdump.pcap", b"\xa1\xb2\xc3\xd4"),
+    file_signature("PCAP capture (nanosecond, little-endian)", "pcap", "application/vnd.tcpdump.pcap", b"\x4d\x3c\xb2\xa1"),
+    file_signature("PCAP capture (nanosecond, big-endian)", "pcap", "application/vnd.tcpdump.pcap", b"\xa1\xb2\x3c\x4d"),
+    checked(file_signature("PCAP-NG capture", "pcapng", "application/x-pcapng", b"\x0a\x0d\x0d\x0a"), is_pcapng),
+    file_signature("SQLite database", "sqlite", "application/vnd.sqlite3", b"SQLite format 3\0"),
+];
+
+fn u16_le(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(data.get(offset..offset + 2)?.try_into().ok()?))
+}
+
+fn u32_le(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn u32_be(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+// Unsigned integer of `size` bytes in either byte order
+fn uint(data: &[u8], offset: usize, size: usize, big_endian: bool) -> Option<u64> {
+    let bytes = data.get(offset..offset.checked_add(size)?)?;
+    let fold = |value: u64, &b: &u8| (value << 8) | b as u64;
+    Some(if big_endian { bytes.iter().fold(0, fold) } else { bytes.iter().rev().fold(0, fold) })
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack.get(from..)?.windows(needle.len()).position(|window| window == needle).map(|i| i + from)
+}
+
+// Offset of the "PE\0\0" header that e_lfanew points to
+fn pe_header(data: &[u8]) -> Option<usize> {
+    let offset = u32_le(data, 0x3c)? as usize;
+    (data.get(offset..offset.checked_add(4)?)? == b"PE\0\0").then_some(offset)
+}
+
+fn is_pe(data: &[u8]) -> bool {
+    pe_header(data).is_some()
+}
+
+fn is_pe_dll(data: &[u8]) -> bool {
+    // IMAGE_FILE_DLL in the COFF header's Characteristics
+    pe_header(data).and_then(|offset| u16_le(data, offset + 22)).is_some_and(|characteristics| characteristics & 0x2000 != 0)
+}
+
+// Java class files and fat Mach-O binaries share CAFEBABE: the next word is the class file
+// version (major 45 or later) or the Mach-O architecture count (small)
+fn is_java_class(data: &[u8]) -> bool {
+    u32_be(data, 4).is_some_and(|version| (45..0x100).contains(&(version & 0xffff)) && version >> 16 < 0x100)
+}
+
+fn is_fat_mach_o(data: &[u8]) -> bool {
+    u32_be(data, 4).is_some_and(|count| (1..=30).contains(&count))
+}
+
+fn is_bmp(data: &[u8]) -> bool {
+    // Reserved fields are zero and the DIB header size is one of the known values
+    u32_le(data, 6) == Some(0) && u32_le(data, 14).is_some_and(|size| matches!(size, 12 | 40 | 52 | 56 | 64 | 108 | 124))
+}
+
+fn is_bzip2(data: &[u8]) -> bool {
+    // Block size digit, then the block header magic (pi in BCD)
+    matches!(data.get(3), Some(b'1'..=b'9')) && data.get(4..10) == Some(b"1AY&SY")
+}
+
+fn is_pcapng(data: &[u8]) -> bool {
+    matches!(data.get(8..12), Some(b"\x4d\x3c\x2b\x1a" | b"\x1a\x2b\x3c\x4d"))
+}
+
+fn zip_has_member(data: &[u8], name: &[u8]) -> bool {
+    data[..data.len().min(ZIP_PROBE_LENGTH)].windows(name.len()).any(|window| window == name)
+}
+
+/// A signature match: `offset` is where the file starts, `magic_offset` where its magic bytes sit.
+pub struct SignatureMatch {
+    pub offset: usize,
+    pub magic_offset: usize,
+    pub signature: &'static FileSignature,
+}
+
+impl SignatureMatch {
+    fn to_json(&self) -> Value {
+        json!({
+            "offset": self.offset,
+            "offset_hex": format!("0x{:x}", self.offset),
+            "magic_offset": self.magic_offset,
+            "name": self.signature.name,
+            "extension": self.signature.extension,
+            "mime": self.signature.mime,
+        })
+    }
+}
+
+fn signature_matches(data: &[u8], offset: usize, signature: &FileSignature) -> bool {
+    data.get(offset + signature.offset..).is_some_and(|rest| rest.starts_with(signature.magic))
+        && signature.check.is_none_or(|check| check(&data[offset..]))
+}
+
+// Signatures sharing a magic are alternatives: keep only the first that passes
+fn push_match(found: &mut Vec<SignatureMatch>, data: &[u8], offset: usize, signature: &'static FileSignature) {
+    let magic_offset = offset + signature.offset;
+    let shadowed = found.iter().rev().take_while(|m| m.magic_offset == magic_offset).any(|m| m.signature.magic == signature.magic);
+    if !shadowed && signature_matches(data, offset, signature) {
+        found.push(SignatureMatch { offset, magic_offset, signature });
+    }
+}
+
+/// Find file signatures at every offset, like binwalk, up to `limit` matches.
+pub fn scan_signatures(data: &[u8], limit: usize) -> Vec<SignatureMatch> {
+    let mut by_first_byte: Vec<Vec<&'static FileSignature>> = vec![Vec::new(); 256];
+    for signature in FILE_SIGNATURES {
+        by_first_byte[signature.magic[0] as usize].push(signature);
+    }
+    let mut found = Vec::new();
+    for (position, &byte) in data.iter().enumerate() {
+        for &signature in &by_first_byte[byte as usize] {
+            if let Some(offset) = position.checked_sub(signature.offset) {
+                push_match(&mut found, data, offset, signature);
+            }
+        }
+        if found.len() >= limit {
+            found.truncate(limit);
+            break;
+        }
+    }
+    found.sort_by_key(|m| m.offset);
+    found
+}
+
+/// Identify the input from its magic bytes, reporting name, MIME type, extension and offset as JSON.
+pub fn detect_file_type(input: &str, input_format: &str) -> Result<String> {
+    let data = parse_bytes(input, input_format)?;
+    if data.is_empty() {
+        return Err(anyhow!("Input is empty"));
+    }
+    let mut found = Vec::new();
+    for signature in FILE_SIGNATURES {
+        push_match(&mut found, &data, 0, signature);
+    }
+    let found: Vec<Value> = found.iter().map(SignatureMatch::to_json).collect();
+    Ok(serde_json::to_string_pretty(&found)?)
+}
+
+/// List every embedded file signature in the input with its offset.
+pub fn scan_for_embedded_files(input: &str, input_format: &str) -> Result<String> {
+    let data = parse_bytes(input, input_format)?;
+    let found: Vec<Value> = scan_signatures(&data, MAX_SCAN_MATCHES).iter().map(SignatureMatch::to_json).collect();
+    Ok(serde_json::to_string_pretty(&found)?)
+}
+
+fn png_length(data: &[u8]) -> Option<usize> {
+    let mut position = 8;
+    loop {
+        let length = u32_be(data, position)? as usize;
+        let chunk_type = data.get(position + 4..position + 8)?;
+        position = position.checked_add(length)?.checked_add(12)?;
+        if chunk_type == b"IEND" {
+            return Some(position);
+        }
+    }
+}
+
+// Walk the marker segments to the scan data, then look for EOI outside it, so embedded EXIF
+// thumbnails don't end the image early
+fn jpeg_length(data: &[u8]) -> Option<usize> {
+    let mut position = 2;
+    loop {
+        if *data.get(position)? != 0xff {
+            return None;
+        }
+        match *data.get(position + 1)? {
+            0xd9 => return Some(position + 2),
+            0xff => position += 1,
+            0x01 | 0xd0..=0xd7 => position += 2,
+            marker => {
+                position += 2 + u16::from_be_bytes([*data.get(position + 2)?, *data.get(position + 3)?]) as usize;
+                if marker == 0xda {
+                    // Entropy-coded data: FF is followed by 00 stuffing or a restart marker
+                    while data.get(position)? != &0xff || matches!(data.get(position + 1)?, 0x00 | 0xd0..=0xd7) {
+                        position += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn zip_length(data: &[u8]) -> Option<usize> {
+    let end = find(data, b"PK\x05\x06", 0)?;
+    Some(end + 22 + u16_le(data, end + 20)? as usize)
+}
+
+// The last %%EOF before any following PDF header, so incremental updates stay attached
+fn pdf_length(data: &[u8]) -> Option<usize> {
+    let limit = find(data, b"%PDF-", 1).unwrap_or(data.len());
+    let end = data[..limit].windows(5).rposition(|window| window == b"%%EOF")? + 5;
+    let newline = data[end..].iter().take(2).take_while(|&&b| b == b'\r' || b == b'\n').count();
+    Some(end + newline)
+}
+
+fn pe_length(data: &[u8]) -> Option<usize> {
+    let pe = pe_header(data)?;
+    let sections = u16_le(data, pe + 6)? as usize;
+    let optional = pe + 24;
+    let section_table = optional + u16_le(data, pe + 20)? as usize;
+    let mut end = u32_le(data, optional + 60)? as usize;
+    for section in 0..sections {
+        let header = section_table + section * 40;
+        end = end.max((u32_le(data, header + 20)? as usize).checked_add(u32_le(data, header + 16)? as usize)?);
+    }
+    // The Authenticode signature sits after the sections, addressed by file offset
+    let directories = match u16_le(data, optional)? {
+        0x20b => optional + 112,
+        _ => optional + 96,
+    };
+    if u32_le(data, directories - 4)? > 4 {
+        end = end.max((u32_le(data, directories + 32)? as usize).checked_add(u32_le(data, directories + 36)? as usize)?);
+    }
+    Some(end)
+}
+
+fn elf_length(data: &[u8]) -> Option<usize> {
+    let wide = *data.get(4)? == 2;
+    let big_endian = *data.get(5)? == 2;
+    let word = if wide { 8 } else { 4 };
+    let field = |offset: usize, size: usize| uint(data, offset, size, big_endian);
+    let (ph, sh) = if wide { (0x20, 0x28) } else { (0x1c, 0x20) };
+    let sizes = if wide { 0x36 } else { 0x2a };
+    let (phoff, shoff) = (field(ph, word)?, field(sh, word)?);
+    let (phentsize, phnum) = (field(sizes, 2)?, field(sizes + 2, 2)?);
+    let (shentsize, shnum) = (field(sizes + 4, 2)?, field(sizes + 6, 2)?);
+    // Header fields are untrusted, so every sum is checked; a table entry must start inside the data
+    let table_end = |offset: u64, entsize: u64, count: u64| offset.checked_add(entsize.checked_mul(count)?);
+    let entry = |offset: u64, entsize: u64, index: u64| {
+        usize::try_from(table_end(offset, entsize, index)?).ok().filter(|&header| header < data.len())
+    };
+    let mut end = table_end(phoff, phentsize, phnum)?.max(table_end(shoff, shentsize, shnum)?);
+    for index in 0..phnum {
+        // p_offset and p_filesz
+        let header = entry(phoff, phentsize, index)?;
+        let (offset, size) = if wide { (field(header + 8, 8)?, field(header + 32, 8)?) } else { (field(header + 4, 4)?, field(header + 16, 4)?) };
+        end = end.max(offset.checked_add(size)?);
+    }
+    for index in 0..shnum {
+        // sh_type, sh_offset and sh_size; SHT_NOBITS sections take no file space
+        let header = entry(shoff, shentsize, index)?;
+        let (offset, size) = if wide { (field(header + 24, 8)?, field(header + 32, 8)?) } else { (field(header + 16, 4)?, field(header + 20, 4)?) };
+        if field(header + 4, 4)? != 8 {
+            end = end.max(offset.checked_add(size)?);
+        }
+    }
+    usize::try_from(end).ok()
+}
+
+fn pcap_length(data: &[u8]) -> Option<usize> {
+    let big_endian = matches!(data.get(..4)?, b"\xa1\xb2\xc3\xd4" | b"\xa1\xb2\x3c\x4d");
+    let mut position = 24;
+    while let Some(captured) = uint(data, position + 8, 4, big_endian) {
+        if captured > MAX_PCAP_RECORD || position + 16 + captured as usize > data.len() {
+            break;
+        }
+        position += 16 + captured as usize;
+    }
+    Some(position)
+}
+
+fn sqlite_length(data: &[u8]) -> Option<usize> {
+    let page_size = match u16::from_be_bytes([*data.get(16)?, *data.get(17)?]) {
+        1 => 65536,
+        size => size as usize,
+    };
+    let pages = u32_be(data, 28)? as usize;
+    (pages > 0).then(|| page_size * pages)
+}
+
+// Size of the file starting at `data`, from its length fields or footer; None when the format
+// gives no reliable way to tell
+fn carved_length(data: &[u8], extension: &str) -> Option<usize> {
+    let length = match extension {
+        "png" => png_length(data),
+        "jpg" => jpeg_length(data),
+        "gif" => find(data, b"\x00\x3b", 6).map(|end| end + 2),
+        "pdf" => pdf_length(data),
+        "zip" | "docx" | "xlsx" | "pptx" | "jar" | "apk" => zip_length(data),
+        "exe" | "dll" => pe_length(data),
+        "elf" => elf_length(data),
+        "bmp" => u32_le(data, 2).map(|size| size as usize),
+        "pcap" => pcap_length(data),
+        "sqlite" => sqlite_length(data),
+        _ => None,
+    };
+    length.filter(|&length| length > 0 && length <= data.len())
+}
+
+struct CarvedFile {
+    offset: usize,
+    length: usize,
+    description: String,
+    extension: &'static str,
+}
+
+fn carve_by_signature(data: &[u8]) -> Vec<CarvedFile> {
+    let found = scan_signatures(data, MAX_CARVED_FILES);
+    found
+        .iter()
+        .map(|m| {
+            let next = found.iter().map(|other| other.offset).find(|&offset| offset > m.offset).unwrap_or(data.len());
+            CarvedFile {
+                offset: m.offset,
+                length: carved_length(&data[m.offset..], m.signature.extension).unwrap_or(next - m.offset),
+                description: m.signature.name.to_string(),
+                extension: m.signature.extension,
+            }
+        })
+        .collect()
+}
+
+// Each header runs to the end of the first footer after it, or to the next header
+fn carve_by_marker(data: &[u8], header: &[u8], footer: &[u8]) -> Vec<CarvedFile> {
+    let mut starts = Vec::new();
+    let mut from = 0;
+    while let Some(start) = find(data, header, from) {
+        starts.push(start);
+        from = start + 1;
+        if starts.len() == MAX_CARVED_FILES {
+            break;
+        }
+    }
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let next = starts.get(i + 1).copied().unwrap_or(data.len());
+            let end = match footer {
+                [] => next,
+                footer => find(data, footer, start + header.len()).map_or(next, |end| end + footer.len()),
+            };
+            CarvedFile { offset: start, length: end - start, description: "Header match".to_string(), extension: "bin" }
+        })
+        .collect()
+}
+
+/// Carve embedded files by signature, or by a custom hex header and optional footer, into a JSON
+/// list of named blobs that `select_carved_file` can pick from.
+pub fn carve_files(input: &str, input_format: &str, header: &str, footer: &str, output_format: &str) -> Result<String> {
+    let data = parse_bytes(input, input_format)?;
+    let carved = match parse_bytes(header, "hex")? {
+        header if header.is_empty() => carve_by_signature(&data),
+        header => carve_by_marker(&data, &header, &parse_bytes(footer, "hex")?),
+    };
+    let total: usize = carved.iter().map(|file| file.length).sum();
+    if total > MAX_CARVED_BYTES {
+        return Err(anyhow!("Carved files total {} bytes, over the limit of {}", total, MAX_CARVED_BYTES));
+    }
+    let encoding = match output_format.to_lowercase().as_str() {
+        format @ ("hex" | "base64") => format.to_string(),
+        other => return Err(anyhow!("Unknown output format: {} (expected base64 or hex)", other)),
+    };
+    let files = carved
+        .iter()
+        .enumerate()
+        .map(|(index, file)| {
+            let blob = &data[file.offset..file.offset + file.length];
+            Ok(json!({
+                "index": index,
+                "name": format!("{:X}.{}", file.offset, file.extension),
+                "type": file.description,
+                "offset": file.offset,
+                "length": file.length,
+                "encoding": encoding,
+                "data": format_bytes(blob, &encoding)?,
+            }))
+        })
+        .collect::<Result<Vec<Value>>>()?;
+    Ok(serde_json::to_string_pretty(&files)?)
+}
+
+/// Pick one blob from `carve_files` output by index and emit its bytes.
+pub fn select_carved_file(input: &str, index: usize, output_format: &str) -> Result<String> {
+    let files: Value = serde_json::from_str(input).map_err(|e| anyhow!("Input is not carve_files output: {}", e))?;
+    let files = files.as_array().ok_or_else(|| anyhow!("Input is not carve_files output"))?;
+    let file = files.get(index).ok_or_else(|| anyhow!("No carved file at index {} ({} available)", index, files.len()))?;
+    let encoding = file["encoding"].as_str().unwrap_or("base64");
+    let data = file["data"].as_str().ok_or_else(|| anyhow!("Carved file {} has no data", index))?;
+    format_bytes(&parse_bytes(data, encoding)?, output_format)
+}
+
+fn is_string_byte(b: u8) -> bool {
+    b.is_ascii_graphic() || b == b' ' || b == b'\t'
+}
+
+// Runs of at least `min_length` printable characters, from (offset, character or None) units
+fn string_runs(units: impl Iterator<Item = (usize, Option<u8>)>, min_length: usize) -> Vec<(usize, String)> {
+    let mut runs = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+    for (offset, unit) in units.map(|(offset, b)| (offset, b.filter(|&b| is_string_byte(b)))).chain([(0, None)]) {
+        match (unit, &mut current) {
+            (Some(b), Some((_, run))) => run.push(b as char),
+            (Some(b), None) => current = Some((offset, (b as char).to_string())),
+            (None, _) => runs.extend(current.take().filter(|(_, run)| run.len() >= min_length)),
+        }
+    }
+    runs
+}
+
+fn utf16le_runs(data: &[u8], min_length: usize) -> Vec<(usize, String)> {
+    // Both alignments, since a string embedded in other data need not start on an even offset
+    (0..2)
+        .flat_map(|start| {
+            let units = data.get(start..).unwrap_or_default().chunks_exact(2).enumerate();
+            string_runs(units.map(|(i, pair)| (start + i * 2, (pair[1] == 0).then_some(pair[0]))), min_length)
+        })
+        .collect()
+}
+
+/// Printable ASCII and/or UTF-16LE strings of at least `min_length` characters, one per line in
+/// file order like `strings(1)`, optionally prefixed with their hex offset and filtered by a regex.
+pub fn extract_strings(input: &str, input_format: &str, min_length: usize, encoding: &str, show_offsets: bool, filter: &str) -> Result<String> {
+    let data = parse_bytes(input, input_format)?;
+    let min_length = min_length.max(1);
+    let mut found = match encoding.to_lowercase().as_str() {
+        "ascii" => string_runs(data.iter().copied().map(Some).enumerate(), min_length),
+        "utf16le" | "utf-16le" => utf16le_runs(&data, min_length),
+        "all" => {
+            let mut found = string_runs(data.iter().copied().map(Some).enumerate(), min_length);
+            found.extend(utf16le_runs(&data, min_length));
+            found
+        }
+        other => return Err(anyhow!("Unknown encoding: {} (expected ascii, utf16le or all)", other)),
+    };
+    found.sort_by_key(|&(offset, _)| offset);
+    if !filter.is_empty() {
+        let pattern = regex::Regex::new(filter).map_err(|e| anyhow!("Invalid filter regex: {}", e))?;
+        found.retain(|(_, string)| pattern.is_match(string));
+    }
+    let lines: Vec<String> = found
+        .into_iter()
+        .map(|(offset, string)| if show_offsets { format!("{:x} {}", offset, string) } else { string })
+        .collect();
+
+    if lines.is_empty() {
+        Ok("No strings found".to_string())
+    } else {
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pe_stub(characteristics: u16) -> Vec<u8> {
+        let mut data = vec![0u8; 0x100];
+        data[..2].copy_from_slice(b"MZ");
+        data[0x3c] = 0x80;
+        data[0x80..0x84].copy_from_slice(b"PE\0\0");
+        data[0x96..0x98].copy_from_slice(&characteristics.to_le_bytes());
+        data
+    }
+
+    fn detect(data: &[u8]) -> Value {
+        serde_json::from_str(&detect_file_type(&hex::encode(data), "hex").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_detect_common_formats() {
+        let cases: &[(&[u8], &str)] = &[
+            (b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", "png"),
+            (b"%PDF-1.7\n", "pdf"),
+            (b"\x7fELF\x02\x01\x01", "elf"),
+            (b"\x1f\x8b\x08\0\0\0\0\0", "gz"),
+            (b"SQLite format 3\0", "sqlite"),
+            (b"\xd4\xc3\xb2\xa1\x02\0\x04\0", "pcap"),
+            (b"\xca\xfe\xba\xbe\0\0\0\x34", "class"),
+            (b"\xca\xfe\xba\xbe\0\0\0\x02", "macho"),
+            (b"PK\x03\x04\x14\0\0\0[Content_Types].xmlword/document.xml", "docx"),
+            (b"PK\x03\x04\x14\0\0\0hello.txt", "zip"),
+        ];
+        for (data, extension) in cases {
+            let found = detect(data);
+            assert_eq!(found[0]["extension"], *extension, "{:?}", found);
+            assert_eq!(found.as_array().unwrap().len(), 1, "{:?}", found);
+        }
+        assert_eq!(detect(&pe_stub(0x0102))[0]["name"], "Windows PE executable");
+        assert_eq!(detect(&pe_stub(0x2102))[0]["mime"], "application/vnd.microsoft.portable-executable");
+        assert_eq!(detect(&pe_stub(0x2102))[0]["extension"], "dll");
+        assert_eq!(detect(b"MZ but not a PE file"), json!([]));
+    }
+
+    #[test]
+    fn test_detect_offset_signature() {
+        let mut tar = vec![0u8; 512];
+        tar[257..263].copy_from_slice(b"ustar\0");
+        let found = detect(&tar);
+        assert_eq!(found[0]["extension"], "tar");
+        assert_eq!(found[0]["offset"], 0);
+        assert_eq!(found[0]["magic_offset"], 257);
+    }
+
+    #[test]
+    fn test_scan_for_embedded_files() {
+        let mut data = b"junk data ".to_vec();
+        data.extend_from_slice(b"\x89PNG\r\n\x1a\n");
+        data.extend_from_slice(b" more ");
+        data.extend(pe_stub(0x0102));
+        let found: Value = serde_json::from_str(&scan_for_embedded_files(&hex::encode(&data), "hex").unwrap()).unwrap();
+        let offsets: Vec<(u64, &str)> =
+            found.as_array().unwrap().iter().map(|m| (m["offset"].as_u64().unwrap(), m["extension"].as_str().unwrap())).collect();
+        assert_eq!(offsets, vec![(10, "png"), (24, "exe")]);
+        assert_eq!(found[1]["offset_hex"], "0x18");
+    }
+
+    fn png(payload: &[u8]) -> Vec<u8> {
+        let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+        for (chunk_type, body) in [(&b"IHDR"[..], payload), (b"IEND", b"")] {
+            data.extend_from_slice(&(body.len() as u32).to_be_bytes());
+            data.extend_from_slice(chunk_type);
+            data.extend_from_slice(body);
+            data.extend_from_slice(&[0; 4]);
+        }
+        data
+    }
+
+    fn carve(data: &[u8], header: &str, footer: &str) -> Value {
+        serde_json::from_str(&carve_files(&hex::encode(data), "hex", header, footer, "hex").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_carve_by_length_fields() {
+        let image = png(b"\0\0\0\x01\0\0\0\x01\x08\x02\0\0\0");
+        let archive = b"PK\x03\x04 member PK\x05\x06\0\0\0\0\x01\0\x01\0\0\0\0\0\0\0\0\0\x02\0hi".to_vec();
+        let data = [&b"head "[..], &image, b" gap ", &archive, b" tail"].concat();
+        let carved = carve(&data, "", "");
+        let files = carved.as_array().unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0]["name"], "5.png");
+        assert_eq!(files[0]["length"], image.len());
+        assert_eq!(files[0]["data"], hex::encode(&image));
+        assert_eq!(files[1]["offset"], 5 + image.len() + 5);
+        assert_eq!(files[1]["length"], archive.len());
+        assert_eq!(files[1]["type"], "ZIP archive");
+    }
+
+    #[test]
+    fn test_carve_survives_hostile_length_fields() {
+        // ELF64 header with e_phoff = u64::MAX and one program header
+        let mut elf = vec![0u8; 0x80];
+        elf[..6].copy_from_slice(b"\x7fELF\x02\x01");
+        elf[0x20..0x28].copy_from_slice(&u64::MAX.to_le_bytes());
+        elf[0x36..0x3a].copy_from_slice(&[56, 0, 1, 0]);
+        assert_eq!(carve(&elf, "", "")[0]["length"], elf.len());
+
+        // ELF64 program header whose p_offset + p_filesz wraps around
+        elf[0x20..0x28].copy_from_slice(&0x40u64.to_le_bytes());
+        elf[0x48..0x50].copy_from_slice(&u64::MAX.to_le_bytes());
+        elf[0x60..0x68].copy_from_slice(&2u64.to_le_bytes());
+        assert_eq!(carve(&elf, "", "")[0]["length"], elf.len());
+
+        let mut pe = pe_stub(0x0102);
+        pe[0x86..0x88].copy_from_slice(&1u16.to_le_bytes());
+        pe[0x94..0x96].copy_from_slice(&0x10u16.to_le_bytes());
+        pe[0xa8 + 16..0xa8 + 24].copy_from_slice(&[0xff; 8]);
+        assert_eq!(carve(&pe, "", "")[0]["length"], pe.len());
+    }
+
+    #[test]
+    fn test_carve_jpeg_skips_stuffed_bytes() {
+        let jpeg = b"\xff\xd8\xff\xe0\0\x04JF\xff\xda\0\x02\x12\xff\0\x34\xff\xd0\x56\xff\xd9";
+        let data = [jpeg.as_slice(), b"trailing"].concat();
+        assert_eq!(carve(&data, "", "")[0]["length"], jpeg.len());
+    }
+
+    #[test]
+    fn test_carve_by_custom_markers() {
+        let carved = carve(b"xxSTARTabcENDyySTARTdef", &hex::encode("START"), &hex::encode("END"));
+        assert_eq!(carved[0]["data"], hex::encode("STARTabcEND"));
+        assert_eq!(carved[0]["name"], "2.bin");
+        assert_eq!(carved[1]["data"], hex::encode("STARTdef"));
+        assert!(carve_files("abc", "utf8", "61", "", "latin1").is_err());
+    }
+
+    #[test]
+    fn test_select_carved_file() {
+        let data = [&b"xx"[..], &png(b"")].concat();
+        let carved = carve_files(&hex::encode(&data), "hex", "", "", "base64").unwrap();
+        assert_eq!(select_carved_file(&carved, 0, "hex").unwrap(), hex::encode(png(b"")));
+        assert!(select_carved_file(&carved, 1, "hex").unwrap_err().to_string().contains("1 available"));
+        assert!(select_carved_file("not json", 0, "hex").is_err());
+    }
+
+    #[test]
+    fn test_extract_strings() {
+        let data = [&b"\x00\x01hello world\x02ab\xffGetProcAddress\x00\x00"[..], &"Wide text".encode_utf16().flat_map(u16::to_le_bytes).collect::<Vec<u8>>()].concat();
+        let input = hex::encode(&data);
+        assert_eq!(extract_strings(&input, "hex", 4, "ascii", false, "").unwrap(), "hello world\nGetProcAddress");
+        assert_eq!(extract_strings(&input, "hex", 4, "utf16le", true, "").unwrap(), "21 Wide text");
+        assert_eq!(extract_strings(&input, "hex", 4, "all", true, "").unwrap(), "2 hello world\n11 GetProcAddress\n21 Wide text");
+        assert_eq!(extract_strings(&input, "hex", 2, "ascii", false, "^[a-z]+$").unwrap(), "ab");
+        assert_eq!(extract_strings(&input, "hex", 20, "all", false, "").unwrap(), "No strings found");
+        assert!(extract_strings(&input, "hex", 4, "ebcdic", false, "").is_err());
+        assert!(extract_strings(&input, "hex", 4, "all", false, "(").is_err());
+    }
+}
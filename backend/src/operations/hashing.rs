@@ -1,9 +1,22 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use blake2::{Blake2b512, Blake2bVar, Blake2s256, Blake2sVar};
 use md5::Md5;
+use ripemd::{Ripemd128, Ripemd160, Ripemd256, Ripemd320};
+use serde_json::{Map, Value};
 use sha1::Sha1;
 use sha2::{Sha256, Sha512, Digest};
+use sha3::{Keccak224, Keccak256, Keccak384, Keccak512, Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256};
+use sm3::Sm3;
+use std::io::Cursor;
+use std::sync::OnceLock;
+use whirlpool::Whirlpool;
 use crc32fast::Hasher;
 
+use super::checksums::{CrcState, CRC_CATALOG};
+
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
 pub fn md5_hash(input: &str) -> Result<String> {
     let mut hasher = Md5::new();
     hasher.update(input.as_bytes());
@@ -32,6 +45,383 @@ pub fn sha512_hash(input: &str) -> Result<String> {
     Ok(format!("{:x}", result))
 }
 
+fn digest_hex<D: Digest>(input: &str) -> String {
+    let mut hasher = D::new();
+    hasher.update(input.as_bytes());
+    let result = hasher.finalize();
+    hex::encode(result)
+}
+
+fn output_bytes(bits: usize, max_bits: usize) -> Result<usize> {
+    if bits == 0 || bits > max_bits || !bits.is_multiple_of(8) {
+        return Err(anyhow!("Output length must be a multiple of 8 between 8 and {} bits", max_bits));
+    }
+    Ok(bits / 8)
+}
+
+pub fn sha3_hash(input: &str, bits: usize) -> Result<String> {
+    match bits {
+        224 => Ok(digest_hex::<Sha3_224>(input)),
+        256 => Ok(digest_hex::<Sha3_256>(input)),
+        384 => Ok(digest_hex::<Sha3_384>(input)),
+        512 => Ok(digest_hex::<Sha3_512>(input)),
+        _ => Err(anyhow!("Unsupported SHA-3 length: {} (expected 224, 256, 384 or 512)", bits)),
+    }
+}
+
+/// Original Keccak padding, as used by Ethereum (differs from standardised SHA-3).
+pub fn keccak_hash(input: &str, bits: usize) -> Result<String> {
+    match bits {
+        224 => Ok(digest_hex::<Keccak224>(input)),
+        256 => Ok(digest_hex::<Keccak256>(input)),
+        384 => Ok(digest_hex::<Keccak384>(input)),
+        512 => Ok(digest_hex::<Keccak512>(input)),
+        _ => Err(anyhow!("Unsupported Keccak length: {} (expected 224, 256, 384 or 512)", bits)),
+    }
+}
+
+pub fn shake_hash(input: &str, variant: u32, output_bits: usize) -> Result<String> {
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+    let mut output = vec![0u8; output_bytes(output_bits, 1 << 16)?];
+    match variant {
+        128 => {
+            let mut hasher = Shake128::default();
+            hasher.update(input.as_bytes());
+            hasher.finalize_xof().read(&mut output);
+        }
+        256 => {
+            let mut hasher = Shake256::default();
+            hasher.update(input.as_bytes());
+            hasher.finalize_xof().read(&mut output);
+        }
+        _ => return Err(anyhow!("Unsupported SHAKE variant: {} (expected 128 or 256)", variant)),
+    }
+    Ok(hex::encode(output))
+}
+
+pub fn blake2b_hash(input: &str, bits: usize) -> Result<String> {
+    use blake2::digest::{Update, VariableOutput};
+
+    let mut output = vec![0u8; output_bytes(bits, 512)?];
+    let mut hasher = Blake2bVar::new(output.len())?;
+    hasher.update(input.as_bytes());
+    hasher.finalize_variable(&mut output)?;
+    Ok(hex::encode(output))
+}
+
+pub fn blake2s_hash(input: &str, bits: usize) -> Result<String> {
+    use blake2::digest::{Update, VariableOutput};
+
+    let mut output = vec![0u8; output_bytes(bits, 256)?];
+    let mut hasher = Blake2sVar::new(output.len())?;
+    hasher.update(input.as_bytes());
+    hasher.finalize_variable(&mut output)?;
+    Ok(hex::encode(output))
+}
+
+pub fn blake3_hash(input: &str, bits: usize) -> Result<String> {
+    let mut output = vec![0u8; output_bytes(bits, 1 << 16)?];
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(input.as_bytes());
+    hasher.finalize_xof().fill(&mut output);
+    Ok(hex::encode(output))
+}
+
+pub fn ripemd_hash(input: &str, bits: usize) -> Result<String> {
+    match bits {
+        128 => Ok(digest_hex::<Ripemd128>(input)),
+        160 => Ok(digest_hex::<Ripemd160>(input)),
+        256 => Ok(digest_hex::<Ripemd256>(input)),
+        320 => Ok(digest_hex::<Ripemd320>(input)),
+        _ => Err(anyhow!("Unsupported RIPEMD length: {} (expected 128, 160, 256 or 320)", bits)),
+    }
+}
+
+pub fn whirlpool_hash(input: &str) -> Result<String> {
+    Ok(digest_hex::<Whirlpool>(input))
+}
+
+pub fn sm3_hash(input: &str) -> Result<String> {
+    Ok(digest_hex::<Sm3>(input))
+}
+
+pub fn xxhash(input: &str, variant: &str, seed: u64) -> Result<String> {
+    let bytes = input.as_bytes();
+    match variant.to_lowercase().as_str() {
+        "xxh32" => Ok(format!("{:08x}", xxhash_rust::xxh32::xxh32(bytes, seed as u32))),
+        "xxh64" => Ok(format!("{:016x}", xxhash_rust::xxh64::xxh64(bytes, seed))),
+        "xxh3" | "xxh3_64" => Ok(format!("{:016x}", xxhash_rust::xxh3::xxh3_64_with_seed(bytes, seed))),
+        "xxh3_128" => Ok(format!("{:032x}", xxhash_rust::xxh3::xxh3_128_with_seed(bytes, seed))),
+        _ => Err(anyhow!("Unsupported xxHash variant: {} (expected xxh32, xxh64, xxh3_64 or xxh3_128)", variant)),
+    }
+}
+
+fn murmur3_hash_bytes(bytes: &[u8], bits: usize) -> String {
+    let mut reader = Cursor::new(bytes);
+    match bits {
+        32 => murmur3::murmur3_32(&mut reader, 0).map(|hash| format!("{:08x}", hash)),
+        _ => murmur3::murmur3_x64_128(&mut reader, 0).map(|hash| hex::encode(hash.to_le_bytes())),
+    }
+    .unwrap_or_default()
+}
+
+pub fn murmur3_hash(input: &str, bits: usize, seed: u32) -> Result<String> {
+    let mut reader = Cursor::new(input.as_bytes());
+    match bits {
+        32 => Ok(format!("{:08x}", murmur3::murmur3_32(&mut reader, seed)?)),
+        // Canonical byte order: h1 then h2, each little-endian
+        128 => {
+            let hash = murmur3::murmur3_x64_128(&mut reader, seed)?;
+            Ok(hex::encode(hash.to_le_bytes()))
+        }
+        _ => Err(anyhow!("Unsupported MurmurHash3 length: {} (expected 32 or 128)", bits)),
+    }
+}
+
+pub fn fnv1a_hash(input: &str, bits: usize) -> Result<String> {
+    match bits {
+        32 => {
+            let hash = input.bytes().fold(0x811c9dc5u32, |hash, b| (hash ^ b as u32).wrapping_mul(0x01000193));
+            Ok(format!("{:08x}", hash))
+        }
+        64 => {
+            let hash = input
+                .bytes()
+                .fold(0xcbf29ce484222325u64, |hash, b| (hash ^ b as u64).wrapping_mul(0x100000001b3));
+            Ok(format!("{:016x}", hash))
+        }
+        _ => Err(anyhow!("Unsupported FNV-1a length: {} (expected 32 or 64)", bits)),
+    }
+}
+
+trait IncrementalHash {
+    fn update(&mut self, data: &[u8]);
+    fn finish(self: Box<Self>) -> String;
+}
+
+struct DigestHash<D>(D);
+
+impl<D: Digest> IncrementalHash for DigestHash<D> {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+
+    fn finish(self: Box<Self>) -> String {
+        hex::encode(self.0.finalize())
+    }
+}
+
+// Adapter for hashers outside the `Digest` trait family
+struct StateHash<S> {
+    state: S,
+    update: fn(&mut S, &[u8]),
+    finish: fn(S) -> String,
+}
+
+impl<S> IncrementalHash for StateHash<S> {
+    fn update(&mut self, data: &[u8]) {
+        (self.update)(&mut self.state, data);
+    }
+
+    fn finish(self: Box<Self>) -> String {
+        (self.finish)(self.state)
+    }
+}
+
+fn digest<D: Digest + 'static>() -> Box<dyn IncrementalHash> {
+    Box::new(DigestHash(D::new()))
+}
+
+fn state<S: 'static>(state: S, update: fn(&mut S, &[u8]), finish: fn(S) -> String) -> Box<dyn IncrementalHash> {
+    Box::new(StateHash { state, update, finish })
+}
+
+fn all_hashers(include_fuzzy: bool) -> Vec<(String, Box<dyn IncrementalHash>)> {
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+    let mut hashers: Vec<(String, Box<dyn IncrementalHash>)> = vec![
+        ("MD5".to_string(), digest::<Md5>()),
+        ("SHA-1".to_string(), digest::<Sha1>()),
+        ("SHA-256".to_string(), digest::<Sha256>()),
+        ("SHA-512".to_string(), digest::<Sha512>()),
+        ("SHA3-224".to_string(), digest::<Sha3_224>()),
+        ("SHA3-256".to_string(), digest::<Sha3_256>()),
+        ("SHA3-384".to_string(), digest::<Sha3_384>()),
+        ("SHA3-512".to_string(), digest::<Sha3_512>()),
+        ("Keccak-224".to_string(), digest::<Keccak224>()),
+        ("Keccak-256".to_string(), digest::<Keccak256>()),
+        ("Keccak-384".to_string(), digest::<Keccak384>()),
+        ("Keccak-512".to_string(), digest::<Keccak512>()),
+        (
+            "SHAKE128-256".to_string(),
+            state(Shake128::default(), |s, d| s.update(d), |s| {
+                let mut output = [0u8; 32];
+                s.finalize_xof().read(&mut output);
+                hex::encode(output)
+            }),
+        ),
+        (
+            "SHAKE256-512".to_string(),
+            state(Shake256::default(), |s, d| s.update(d), |s| {
+                let mut output = [0u8; 64];
+                s.finalize_xof().read(&mut output);
+                hex::encode(output)
+            }),
+        ),
+        ("BLAKE2b-512".to_string(), digest::<Blake2b512>()),
+        ("BLAKE2s-256".to_string(), digest::<Blake2s256>()),
+        (
+            "BLAKE3".to_string(),
+            state(blake3::Hasher::new(), |s, d| { s.update(d); }, |s| s.finalize().to_hex().to_string()),
+        ),
+        ("RIPEMD-128".to_string(), digest::<Ripemd128>()),
+        ("RIPEMD-160".to_string(), digest::<Ripemd160>()),
+        ("RIPEMD-256".to_string(), digest::<Ripemd256>()),
+        ("RIPEMD-320".to_string(), digest::<Ripemd320>()),
+        ("Whirlpool".to_string(), digest::<Whirlpool>()),
+        ("SM3".to_string(), digest::<Sm3>()),
+        (
+            "xxHash32".to_string(),
+            state(xxhash_rust::xxh32::Xxh32::new(0), |s, d| s.update(d), |s| format!("{:08x}", s.digest())),
+        ),
+        (
+            "xxHash64".to_string(),
+            state(xxhash_rust::xxh64::Xxh64::new(0), |s, d| s.update(d), |s| format!("{:016x}", s.digest())),
+        ),
+        (
+            "XXH3-64".to_string(),
+            state(xxhash_rust::xxh3::Xxh3::new(), |s, d| s.update(d), |s| format!("{:016x}", s.digest())),
+        ),
+        (
+            "XXH3-128".to_string(),
+            state(xxhash_rust::xxh3::Xxh3::new(), |s, d| s.update(d), |s| format!("{:032x}", s.digest128())),
+        ),
+        (
+            "FNV-1a-32".to_string(),
+            state(
+                0x811c9dc5u32,
+                |s, d| *s = d.iter().fold(*s, |hash, &b| (hash ^ b as u32).wrapping_mul(0x01000193)),
+                |s| format!("{:08x}", s),
+            ),
+        ),
+        (
+            "FNV-1a-64".to_string(),
+            state(
+                0xcbf29ce484222325u64,
+                |s, d| *s = d.iter().fold(*s, |hash, &b| (hash ^ b as u64).wrapping_mul(0x100000001b3)),
+                |s| format!("{:016x}", s),
+            ),
+        ),
+        (
+            "Adler-32".to_string(),
+            state(adler::Adler32::new(), |s, d| s.write_slice(d), |s| format!("{:08x}", s.checksum())),
+        ),
+        (
+            "Fletcher-16".to_string(),
+            state(
+                (0u32, 0u32),
+                |(sum1, sum2), d| {
+                    for &b in d {
+                        *sum1 = (*sum1 + b as u32) % 255;
+                        *sum2 = (*sum2 + *sum1) % 255;
+                    }
+                },
+                |(sum1, sum2)| format!("{:04x}", (sum2 << 8) | sum1),
+            ),
+        ),
+        (
+            // 16-bit words may straddle chunks, so an odd trailing byte is held back until the next one
+            "Fletcher-32".to_string(),
+            state(
+                (0u64, 0u64, None::<u8>),
+                |(sum1, sum2, pending), d| {
+                    let mut add = |word: u64| {
+                        *sum1 = (*sum1 + word) % 65535;
+                        *sum2 = (*sum2 + *sum1) % 65535;
+                    };
+                    let mut rest = d;
+                    if let (Some(low), [high, tail @ ..]) = (*pending, rest) {
+                        add(low as u64 | (*high as u64) << 8);
+                        *pending = None;
+                        rest = tail;
+                    }
+                    let words = rest.chunks_exact(2);
+                    *pending = pending.or(words.remainder().first().copied());
+                    words.for_each(|word| add(word[0] as u64 | (word[1] as u64) << 8));
+                },
+                |(mut sum1, mut sum2, pending)| {
+                    if let Some(low) = pending {
+                        sum1 = (sum1 + low as u64) % 65535;
+                        sum2 = (sum2 + sum1) % 65535;
+                    }
+                    format!("{:08x}", (sum2 << 16) | sum1)
+                },
+            ),
+        ),
+        // The murmur3 crate only hashes whole readers, so these buffer the input
+        (
+            "Murmur3-32".to_string(),
+            state(Vec::new(), |s, d| s.extend_from_slice(d), |s| murmur3_hash_bytes(&s, 32)),
+        ),
+        (
+            "Murmur3-x64-128".to_string(),
+            state(Vec::new(), |s, d| s.extend_from_slice(d), |s| murmur3_hash_bytes(&s, 128)),
+        ),
+    ];
+
+    for (name, params, _) in CRC_CATALOG {
+        hashers.push((name.to_uppercase(), state(CrcState::new(*params), |s, d| s.update(d), |s| s.finish_hex())));
+    }
+
+    if include_fuzzy {
+        hashers.push((
+            "ssdeep".to_string(),
+            state(fuzzyhash::FuzzyHash::default(), |s, d| s.update(d), |mut s| {
+                s.finalize();
+                s.to_string()
+            }),
+        ));
+        hashers.push((
+            "TLSH".to_string(),
+            state(tlsh2::TlshDefaultBuilder::new(), |s, d| s.update(d), |s| match s.build() {
+                Some(tlsh) => String::from_utf8_lossy(&tlsh.hash()).into_owned(),
+                None => "n/a (needs at least 50 bytes with enough variation)".to_string(),
+            }),
+        ));
+    }
+
+    hashers
+}
+
+/// Compute every supported digest and checksum in one pass over the input, with the seeded
+/// hashes (xxHash, Murmur3) at seed 0.
+pub fn generate_all_hashes(input: &str, format: &str, include_fuzzy: bool) -> Result<String> {
+    let mut hashers = all_hashers(include_fuzzy);
+    for chunk in input.as_bytes().chunks(HASH_CHUNK_SIZE) {
+        for (_, hasher) in hashers.iter_mut() {
+            hasher.update(chunk);
+        }
+    }
+    let results: Vec<(String, String)> = hashers.into_iter().map(|(name, hasher)| (name, hasher.finish())).collect();
+
+    match format.to_lowercase().as_str() {
+        "table" => {
+            let width = results.iter().map(|(name, _)| name.len()).max().unwrap_or(0) + 1;
+            let lines: Vec<String> = results
+                .iter()
+                .map(|(name, hash)| format!("{:<width$} {}", format!("{}:", name), hash))
+                .collect();
+            Ok(lines.join("\n"))
+        }
+        "json" => {
+            let map: Map<String, Value> = results.into_iter().map(|(name, hash)| (name, Value::String(hash))).collect();
+            Ok(serde_json::to_string_pretty(&map)?)
+        }
+        _ => Err(anyhow!("Unknown output format: {} (expected table or json)", format)),
+    }
+}
+
 pub fn crc32_hash(input: &str) -> Result<String> {
     let mut hasher = Hasher::new();
     hasher.update(input.as_bytes());
@@ -82,9 +472,174 @@ fn calculate_simple_hash(input: &str) -> u64 {
     hash
 }
 
+// (name, hashcat mode, John the Ripper format)
+type HashCandidate = (&'static str, Option<u32>, Option<&'static str>);
+
+// Structured formats, checked before falling back to bare digest lengths
+const HASH_SIGNATURES: &[(&str, &[HashCandidate])] = &[
+    (r"^\$2[abxy]?\$\d{2}\$[./A-Za-z0-9]{53}$", &[("bcrypt", Some(3200), Some("bcrypt"))]),
+    (r"^\$argon2(id|i|d)\$", &[("Argon2", Some(34000), Some("argon2"))]),
+    (r"^\$1\$[^$]{0,8}\$[./A-Za-z0-9]{22}$", &[("md5crypt (Unix $1$)", Some(500), Some("md5crypt"))]),
+    (r"^\$apr1\$[^$]{0,8}\$[./A-Za-z0-9]{22}$", &[("Apache apr1 MD5", Some(1600), Some("md5crypt"))]),
+    (r"^\$5\$(rounds=\d+\$)?[^$]{0,16}\$[./A-Za-z0-9]{43}$", &[("sha256crypt (Unix $5$)", Some(7400), Some("sha256crypt"))]),
+    (r"^\$6\$(rounds=\d+\$)?[^$]{0,16}\$[./A-Za-z0-9]{86}$", &[("sha512crypt (Unix $6$)", Some(1800), Some("sha512crypt"))]),
+    (r"^\$y\$", &[("yescrypt", None, Some("crypt"))]),
+    (r"^\$scrypt\$", &[("scrypt (PHC)", None, None)]),
+    (r"^SCRYPT:\d+:\d+:\d+:", &[("scrypt", Some(8900), Some("scrypt"))]),
+    (r"^\$pbkdf2-sha256\$", &[("PBKDF2-HMAC-SHA256 (PHC/passlib)", Some(20300), Some("PBKDF2-HMAC-SHA256"))]),
+    (r"^\$pbkdf2-sha512\$", &[("PBKDF2-HMAC-SHA512 (PHC/passlib)", Some(20200), Some("PBKDF2-HMAC-SHA512"))]),
+    (r"^\$pbkdf2\$", &[("PBKDF2-HMAC-SHA1 (PHC/passlib)", Some(20400), Some("PBKDF2-HMAC-SHA1"))]),
+    (r"^sha256:\d+:[A-Za-z0-9+/=]+:[A-Za-z0-9+/=]+$", &[("PBKDF2-HMAC-SHA256", Some(10900), Some("PBKDF2-HMAC-SHA256"))]),
+    (r"^pbkdf2_sha256\$\d+\$", &[("Django PBKDF2-SHA256", Some(10000), Some("django"))]),
+    (r"^\$P\$[./A-Za-z0-9]{31}$", &[("phpass (WordPress)", Some(400), Some("phpass"))]),
+    (r"^\$H\$[./A-Za-z0-9]{31}$", &[("phpass (phpBB3)", Some(400), Some("phpass"))]),
+    (r"^\$S\$[./A-Za-z0-9]{52}$", &[("Drupal 7", Some(7900), Some("drupal7"))]),
+    (r"(?i)^\{SHA\}", &[("SHA-1 (Base64, LDAP)", Some(101), Some("nsldap"))]),
+    (r"(?i)^\{SSHA\}", &[("Salted SHA-1 (Base64, LDAP)", Some(111), Some("salted-sha1"))]),
+    (r"(?i)^\{SSHA256\}", &[("Salted SHA-256 (Base64, LDAP)", Some(1411), None)]),
+    (r"(?i)^\{SSHA512\}", &[("Salted SHA-512 (Base64, LDAP)", Some(1711), Some("ssha512"))]),
+    (r"(?i)^\$DCC2\$\d+#[^#]+#[0-9a-f]{32}$", &[("Domain Cached Credentials 2 (MS Cache v2)", Some(2100), Some("mscash2"))]),
+    (r"^\$krb5tgs\$23\$", &[("Kerberos 5 TGS-REP etype 23", Some(13100), Some("krb5tgs"))]),
+    (r"^\$krb5asrep\$23\$", &[("Kerberos 5 AS-REP etype 23", Some(18200), Some("krb5asrep"))]),
+    (r"(?i)^[^:]+::[^:]*:[0-9a-f]{16}:[0-9a-f]{32}:[0-9a-f]+$", &[("NetNTLMv2", Some(5600), Some("netntlmv2"))]),
+    (r"(?i)^[^:]+::[^:]*:[0-9a-f]{48}:[0-9a-f]{48}:[0-9a-f]{16}$", &[("NetNTLMv1", Some(5500), Some("netntlm"))]),
+    (
+        r"(?i)^[^:]*:\d+:[0-9a-f]{32}:[0-9a-f]{32}:*$",
+        &[("NTLM (pwdump user:rid:lm:nt)", Some(1000), Some("nt")), ("LM (pwdump user:rid:lm:nt)", Some(3000), Some("lm"))],
+    ),
+    (r"(?i)^0x0100[0-9a-f]{48}$", &[("MSSQL 2005", Some(132), Some("mssql05"))]),
+    (r"(?i)^0x0200[0-9a-f]{136}$", &[("MSSQL 2012/2014", Some(1731), Some("mssql12"))]),
+    (r"^\*[0-9A-Fa-f]{40}$", &[("MySQL 4.1+", Some(300), Some("mysql-sha1"))]),
+    (r"(?i)^md5[0-9a-f]{32}$", &[("PostgreSQL MD5", Some(12), Some("postgres"))]),
+    (r"(?i)^[0-9a-f]{32}:[^:]+$", &[
+        ("Domain Cached Credentials (MS Cache)", Some(1100), Some("mscash")),
+        ("md5($pass.$salt)", Some(10), None),
+        ("md5($salt.$pass)", Some(20), None),
+    ]),
+    (r"(?i)^[0-9a-f]{40}:[^:]+$", &[("sha1($pass.$salt)", Some(110), None), ("sha1($salt.$pass)", Some(120), None)]),
+];
+
+// Unsalted digests by hex length, most common first
+const DIGEST_LENGTHS: &[(usize, &[HashCandidate])] = &[
+    (8, &[("CRC-32", Some(11500), Some("crc32")), ("Adler-32", None, None), ("FNV-1a-32", None, None), ("xxHash32", None, None)]),
+    (16, &[
+        ("MySQL 3.23", Some(200), Some("mysql")),
+        ("Half MD5", Some(5100), None),
+        ("CRC-64", None, None),
+        ("FNV-1a-64", None, None),
+        ("xxHash64", None, None),
+    ]),
+    (32, &[
+        ("MD5", Some(0), Some("raw-md5")),
+        ("NTLM", Some(1000), Some("nt")),
+        ("MD4", Some(900), Some("raw-md4")),
+        ("LM", Some(3000), Some("lm")),
+        ("md5(md5($pass))", Some(2600), None),
+        ("RIPEMD-128", None, Some("ripemd-128")),
+        ("XXH3-128", None, None),
+    ]),
+    (40, &[
+        ("SHA-1", Some(100), Some("raw-sha1")),
+        ("RIPEMD-160", Some(6000), Some("ripemd-160")),
+        ("MySQL 4.1+ (without *)", Some(300), Some("mysql-sha1")),
+        ("sha1(sha1($pass))", Some(4500), None),
+    ]),
+    (56, &[
+        ("SHA-224", Some(1300), Some("raw-sha224")),
+        ("SHA3-224", Some(17300), None),
+        ("Keccak-224", Some(17700), None),
+    ]),
+    (64, &[
+        ("SHA-256", Some(1400), Some("raw-sha256")),
+        ("SHA3-256", Some(17400), None),
+        ("Keccak-256", Some(17800), Some("raw-keccak-256")),
+        ("BLAKE2s-256", None, None),
+        ("BLAKE3", None, None),
+        ("GOST R 34.11-94", Some(6900), Some("gost")),
+        ("SM3", None, None),
+        ("RIPEMD-256", None, None),
+    ]),
+    (80, &[("RIPEMD-320", None, None)]),
+    (96, &[
+        ("SHA-384", Some(10800), Some("raw-sha384")),
+        ("SHA3-384", Some(17500), None),
+        ("Keccak-384", Some(17900), None),
+    ]),
+    (128, &[
+        ("SHA-512", Some(1700), Some("raw-sha512")),
+        ("SHA3-512", Some(17600), Some("raw-sha3")),
+        ("Keccak-512", Some(18000), Some("raw-keccak")),
+        ("BLAKE2b-512", Some(600), Some("raw-blake2")),
+        ("Whirlpool", Some(6100), Some("whirlpool")),
+    ]),
+];
+
+fn hash_signatures() -> &'static [(regex::Regex, &'static [HashCandidate])] {
+    static SIGNATURES: OnceLock<Vec<(regex::Regex, &'static [HashCandidate])>> = OnceLock::new();
+    SIGNATURES.get_or_init(|| {
+        HASH_SIGNATURES
+            .iter()
+            .map(|(pattern, candidates)| (regex::Regex::new(pattern).unwrap(), *candidates))
+            .collect()
+    })
+}
+
+fn digest_candidates(hex_length: usize) -> &'static [HashCandidate] {
+    DIGEST_LENGTHS
+        .iter()
+        .find(|(length, _)| *length == hex_length)
+        .map(|(_, candidates)| *candidates)
+        .unwrap_or(&[])
+}
+
+/// List likely algorithms for a hash, with hashcat modes and John the Ripper formats.
+pub fn identify_hash(input: &str) -> Result<String> {
+    let hash = input.trim();
+    if hash.is_empty() {
+        return Err(anyhow!("Input is empty"));
+    }
+
+    let mut candidates: Vec<(String, Option<u32>, Option<&str>)> = hash_signatures()
+        .iter()
+        .filter(|(pattern, _)| pattern.is_match(hash))
+        .flat_map(|(_, candidates)| candidates.iter())
+        .map(|&(name, hashcat, john)| (name.to_string(), hashcat, john))
+        .collect();
+
+    if candidates.is_empty() {
+        if hash.chars().all(|c| c.is_ascii_hexdigit()) {
+            candidates.extend(digest_candidates(hash.len()).iter().map(|&(name, hashcat, john)| (name.to_string(), hashcat, john)));
+        } else if let Ok(bytes) = STANDARD.decode(hash) {
+            candidates.extend(
+                digest_candidates(bytes.len() * 2)
+                    .iter()
+                    .map(|&(name, _, _)| (format!("{} (Base64)", name), None, None)),
+            );
+        }
+    }
+
+    if candidates.is_empty() {
+        return Ok(format!("Unknown hash format (length {})", hash.len()));
+    }
+
+    let lines: Vec<String> = candidates
+        .into_iter()
+        .map(|(name, hashcat, john)| {
+            format!(
+                "{}\tHashcat: {}\tJohn: {}",
+                name,
+                hashcat.map(|mode| mode.to_string()).unwrap_or_else(|| "-".to_string()),
+                john.unwrap_or("-")
+            )
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::operations::checksums::fletcher_checksum;
 
     #[test]
     fn test_md5_hash() {
@@ -98,10 +653,109 @@ mod tests {
         assert_eq!(result, "dffd6021bb2bd5b0af676290809ec3a53191dd81c7f70a4b28688a362182986f");
     }
 
+    #[test]
+    fn test_sha3_and_keccak() {
+        assert_eq!(sha3_hash("abc", 256).unwrap(), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+        assert_eq!(sha3_hash("abc", 512).unwrap(), "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0");
+        assert_eq!(keccak_hash("", 256).unwrap(), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        assert!(sha3_hash("abc", 100).is_err());
+    }
+
+    #[test]
+    fn test_shake_hash() {
+        assert_eq!(shake_hash("abc", 128, 256).unwrap(), "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8");
+        assert_eq!(shake_hash("abc", 256, 512).unwrap(), "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4");
+    }
+
+    #[test]
+    fn test_blake_hashes() {
+        assert_eq!(blake2b_hash("abc", 512).unwrap(), "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923");
+        assert_eq!(blake2b_hash("abc", 256).unwrap(), "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319");
+        assert_eq!(blake2s_hash("abc", 256).unwrap(), "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982");
+        assert_eq!(blake2s_hash("abc", 128).unwrap(), "aa4938119b1dc7b87cbad0ffd200d0ae");
+        assert_eq!(blake3_hash("", 256).unwrap(), "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262");
+    }
+
+    #[test]
+    fn test_ripemd_whirlpool_sm3() {
+        assert_eq!(ripemd_hash("abc", 160).unwrap(), "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+        assert_eq!(ripemd_hash("abc", 128).unwrap(), "c14a12199c66e4ba84636b0f69144c77");
+        assert_eq!(whirlpool_hash("abc").unwrap(), "4e2448a4c6f486bb16b6562c73b4020bf3043e3a731bce721ae1b303d97e6d4c7181eebdb6c57e277d0e34957114cbd6c797fc9d95d8b582d225292076d4eef5");
+        assert_eq!(sm3_hash("abc").unwrap(), "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0");
+    }
+
+    #[test]
+    fn test_non_cryptographic_hashes() {
+        assert_eq!(xxhash("", "xxh32", 0).unwrap(), "02cc5d05");
+        assert_eq!(xxhash("", "xxh64", 0).unwrap(), "ef46db3751d8e999");
+        assert_eq!(xxhash("", "xxh3_64", 0).unwrap(), "2d06800538d394c2");
+        assert_eq!(murmur3_hash("hello", 32, 0).unwrap(), "248bfa47");
+        assert_eq!(murmur3_hash("hello", 128, 0).unwrap(), "029bbd41b3a7d8cb191dae486a901e5b");
+        assert_eq!(fnv1a_hash("a", 32).unwrap(), "e40c292c");
+        assert_eq!(fnv1a_hash("a", 64).unwrap(), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_generate_all_hashes_matches_individual() {
+        let input = "The quick brown fox jumps over the lazy dog";
+        let result = generate_all_hashes(input, "json", false).unwrap();
+        let hashes: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(hashes["MD5"], md5_hash(input).unwrap());
+        assert_eq!(hashes["SHA-512"], sha512_hash(input).unwrap());
+        assert_eq!(hashes["SHA3-256"], sha3_hash(input, 256).unwrap());
+        assert_eq!(hashes["SHAKE256-512"], shake_hash(input, 256, 512).unwrap());
+        assert_eq!(hashes["BLAKE3"], blake3_hash(input, 256).unwrap());
+        assert_eq!(hashes["XXH3-128"], xxhash(input, "xxh3_128", 0).unwrap());
+        assert_eq!(hashes["FNV-1a-64"], fnv1a_hash(input, 64).unwrap());
+        assert_eq!(hashes["CRC-32"], crc32_hash(input).unwrap());
+        assert_eq!(hashes["Adler-32"], "5bdc0fda");
+        assert_eq!(hashes["Fletcher-16"], fletcher_checksum(input, 16).unwrap());
+        assert_eq!(hashes["Fletcher-32"], fletcher_checksum(input, 32).unwrap());
+        assert_eq!(hashes["Murmur3-32"], murmur3_hash(input, 32, 0).unwrap());
+        assert_eq!(hashes["Murmur3-x64-128"], murmur3_hash(input, 128, 0).unwrap());
+        assert!(hashes.get("ssdeep").is_none());
+    }
+
+    #[test]
+    fn test_generate_all_hashes_chunked_and_fuzzy() {
+        let input = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(2000);
+        assert!(input.len() > HASH_CHUNK_SIZE);
+        let result = generate_all_hashes(&input, "table", true).unwrap();
+        let sha256 = sha256_hash(&input).unwrap();
+        assert!(result.lines().any(|l| l.starts_with("SHA-256:") && l.ends_with(&sha256)));
+        let fletcher = fletcher_checksum(&input, 32).unwrap();
+        assert!(result.lines().any(|l| l.starts_with("Fletcher-32:") && l.ends_with(&fletcher)));
+        assert!(result.lines().any(|l| l.starts_with("ssdeep:") && l.matches(':').count() == 3));
+        assert!(result.lines().any(|l| l.starts_with("TLSH:") && l.contains(" T1")));
+        assert!(generate_all_hashes("abc", "table", true).unwrap().contains("TLSH:"));
+    }
+
     #[test]
     fn test_crc32_hash() {
         let result = crc32_hash("Hello, World!").unwrap();
         // CRC32 result will depend on the specific implementation
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn test_identify_hash_structured() {
+        let bcrypt = identify_hash("$2b$04$EGdrhbKUv8Oc9vGiXX0HQOxSg445d458Muh7DAHskb6QbtCvdxcie").unwrap();
+        assert_eq!(bcrypt, "bcrypt\tHashcat: 3200\tJohn: bcrypt");
+        assert!(identify_hash("$argon2id$v=19$m=64,t=1,p=1$c2FsdHNhbHQ$aGFzaA").unwrap().starts_with("Argon2\t"));
+        assert!(identify_hash("{SSHA}x5gRMNGudy5ZZ8Xo6IKYodSw2pRKSjK8").unwrap().contains("Hashcat: 111"));
+
+        let pwdump = identify_hash("Administrator:500:aad3b435b51404eeaad3b435b51404ee:31d6cfe0d16ae931b73c59d7e0c089c0:::").unwrap();
+        assert!(pwdump.contains("NTLM (pwdump user:rid:lm:nt)\tHashcat: 1000\tJohn: nt"));
+        assert!(pwdump.contains("Hashcat: 3000"));
+    }
+
+    #[test]
+    fn test_identify_hash_digests() {
+        let md5 = identify_hash(&md5_hash("hello").unwrap()).unwrap();
+        assert!(md5.starts_with("MD5\tHashcat: 0\tJohn: raw-md5"));
+        assert!(md5.contains("NTLM"));
+        assert!(identify_hash(&sha256_hash("hello").unwrap().to_uppercase()).unwrap().starts_with("SHA-256\t"));
+        assert!(identify_hash("qvTGHdzF6KLavt4PO0gs2a6pQ00=").unwrap().starts_with("SHA-1 (Base64)\tHashcat: -"));
+        assert_eq!(identify_hash("not a hash").unwrap(), "Unknown hash format (length 10)");
+    }
 }
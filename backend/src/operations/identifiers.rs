@@ -0,0 +1,141 @@
+use anyhow::{anyhow, Result};
+use chrono::DateTime;
+use uuid::{Uuid, Variant};
+
+const MAX_UUID_COUNT: usize = 10_000;
+
+fn parse_namespace(namespace: &str) -> Result<Uuid> {
+    match namespace.trim().to_lowercase().as_str() {
+        "dns" => Ok(Uuid::NAMESPACE_DNS),
+        "url" => Ok(Uuid::NAMESPACE_URL),
+        "oid" => Ok(Uuid::NAMESPACE_OID),
+        "x500" => Ok(Uuid::NAMESPACE_X500),
+        other => Uuid::parse_str(other).map_err(|e| anyhow!("Invalid namespace '{}': {}", namespace, e)),
+    }
+}
+
+pub fn generate_uuid(version: u8, count: usize, namespace: &str, name: &str) -> Result<String> {
+    if count == 0 || count > MAX_UUID_COUNT {
+        return Err(anyhow!("Count must be between 1 and {}", MAX_UUID_COUNT));
+    }
+
+    let mut uuids = Vec::with_capacity(count);
+    for _ in 0..count {
+        let uuid = match version {
+            1 => {
+                // No real MAC is available, so use a random node ID with the multicast bit set (RFC 4122 4.5)
+                let mut node_id: [u8; 6] = rand::random();
+                node_id[0] |= 0x01;
+                Uuid::now_v1(&node_id)
+            }
+            4 => Uuid::new_v4(),
+            5 => Uuid::new_v5(&parse_namespace(namespace)?, name.as_bytes()),
+            7 => Uuid::now_v7(),
+            _ => return Err(anyhow!("Unsupported UUID version: {} (expected 1, 4, 5 or 7)", version)),
+        };
+        uuids.push(uuid.hyphenated().to_string());
+    }
+
+    Ok(uuids.join("\n"))
+}
+
+fn version_name(version: usize) -> &'static str {
+    match version {
+        1 => "Time-based (Gregorian, MAC)",
+        2 => "DCE Security",
+        3 => "Name-based (MD5)",
+        4 => "Random",
+        5 => "Name-based (SHA-1)",
+        6 => "Reordered time-based (Gregorian, MAC)",
+        7 => "Unix epoch time-based",
+        8 => "Custom",
+        _ => "Unknown",
+    }
+}
+
+fn variant_name(variant: Variant) -> &'static str {
+    match variant {
+        Variant::NCS => "NCS (reserved)",
+        Variant::RFC4122 => "RFC 4122 / RFC 9562",
+        Variant::Microsoft => "Microsoft (reserved)",
+        Variant::Future => "Future (reserved)",
+        _ => "Unknown",
+    }
+}
+
+pub fn analyze_uuid(input: &str) -> Result<String> {
+    let uuid = Uuid::parse_str(input.trim())?;
+    let version = uuid.get_version_num();
+
+    let mut lines = vec![
+        format!("UUID: {}", uuid.hyphenated()),
+        format!("Version: {} ({})", version, version_name(version)),
+        format!("Variant: {}", variant_name(uuid.get_variant())),
+    ];
+
+    if uuid.is_nil() {
+        lines.push("Note: nil UUID".to_string());
+    } else if uuid.is_max() {
+        lines.push("Note: max UUID".to_string());
+    }
+
+    if let Some(timestamp) = uuid.get_timestamp() {
+        let (secs, nanos) = timestamp.to_unix();
+        let time = DateTime::from_timestamp(secs as i64, nanos)
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S%.f UTC").to_string())
+            .unwrap_or_else(|| "out of range".to_string());
+        lines.push(format!("Timestamp: {}", time));
+        lines.push(format!("Unix time: {}.{:09}", secs, nanos));
+    }
+
+    if matches!(version, 1 | 6) {
+        let bytes = uuid.as_bytes();
+        let clock_seq = u16::from_be_bytes([bytes[8], bytes[9]]) & 0x3fff;
+        lines.push(format!("Clock sequence: {}", clock_seq));
+    }
+
+    if let Some(node_id) = uuid.get_node_id() {
+        let mac: Vec<String> = node_id.iter().map(|b| format!("{:02x}", b)).collect();
+        let kind = if node_id[0] & 0x01 != 0 { "random (multicast bit set)" } else { "MAC address" };
+        lines.push(format!("Node ID: {} ({})", mac.join(":"), kind));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_uuid_v4_count() {
+        let result = generate_uuid(4, 3, "", "").unwrap();
+        let uuids: Vec<&str> = result.lines().collect();
+        assert_eq!(uuids.len(), 3);
+        for uuid in uuids {
+            assert_eq!(Uuid::parse_str(uuid).unwrap().get_version_num(), 4);
+        }
+    }
+
+    #[test]
+    fn test_generate_uuid_v5() {
+        let result = generate_uuid(5, 1, "dns", "www.example.com").unwrap();
+        assert_eq!(result, "2ed6657d-e927-568b-95e1-2665a8aea6a2");
+    }
+
+    #[test]
+    fn test_analyze_uuid_v1() {
+        let result = analyze_uuid("c232ab00-9414-11ec-b3c8-9f6bdeced846").unwrap();
+        assert!(result.contains("Version: 1"));
+        assert!(result.contains("Timestamp: 2022-02-22 19:22:22"));
+        assert!(result.contains("Node ID: 9f:6b:de:ce:d8:46"));
+    }
+
+    #[test]
+    fn test_analyze_uuid_v7() {
+        let result = analyze_uuid("017f22e2-79b0-7cc3-98c4-dc0c0c07398f").unwrap();
+        assert!(result.contains("Version: 7"));
+        assert!(result.contains("Timestamp: 2022-02-22 19:22:22"));
+        assert!(!result.contains("Node ID"));
+    }
+}
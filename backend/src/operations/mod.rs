@@ -1,11 +1,35 @@
 pub mod encoding;
 pub mod hashing;
+pub mod checksums;
 pub mod text;
 pub mod crypto;
 pub mod data;
+pub mod identifiers;
+pub mod charsets;
+pub mod unicode;
+pub mod network;
+pub mod passwords;
+pub mod cryptanalysis;
+pub mod certificates;
+pub mod otp;
+pub mod analysis;
+pub mod forensics;
+pub mod executables;
 
 pub use encoding::*;
 pub use hashing::*;
+pub use checksums::*;
 pub use text::*;
 pub use crypto::*;
 pub use data::*;
+pub use identifiers::*;
+pub use charsets::*;
+pub use unicode::*;
+pub use network::*;
+pub use passwords::*;
+pub use cryptanalysis::*;
+pub use certificates::*;
+pub use otp::*;
+pub use analysis::*;
+pub use forensics::*;
+pub use executables::*;
@@ -0,0 +1,534 @@
+use anyhow::{anyhow, Result};
+use argon2::password_hash::{self, PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use hkdf::SimpleHkdf;
+use hmac::SimpleHmac;
+use md5::Md5;
+use pbkdf2::Pbkdf2;
+use scrypt::Scrypt;
+use sha1::Sha1;
+use sha2::{digest::core_api::BlockSizeUser, Digest, Sha256, Sha384, Sha512};
+
+use super::encoding::{format_bytes, parse_bytes};
+
+// Server-side ceilings so a single request can't pin the CPU or exhaust memory
+const MAX_BCRYPT_COST: u32 = 14;
+const MAX_SCRYPT_LOG_N: u8 = 17;
+const MAX_SCRYPT_MEMORY: u64 = 128 * 1024 * 1024;
+const MAX_SCRYPT_PARALLELISM: u32 = 4;
+const MAX_ARGON2_MEMORY_KIB: u32 = 128 * 1024;
+const MAX_ARGON2_ITERATIONS: u32 = 16;
+const MAX_ARGON2_PARALLELISM: u32 = 8;
+const MAX_PBKDF2_ITERATIONS: u32 = 2_000_000;
+const MAX_CRYPT_ROUNDS: u32 = 1_000_000;
+const MAX_DERIVED_KEY_LENGTH: usize = 1024;
+const MAX_EVP_ITERATIONS: u32 = 1_000_000;
+
+/// Tuning knobs for `password_hash`; `None` selects the algorithm's default.
+#[derive(Debug, Clone, Default)]
+pub struct PasswordParams {
+    /// bcrypt cost, scrypt log2(N), Argon2 iterations, PBKDF2 iterations or crypt rounds
+    pub cost: Option<u32>,
+    pub memory_kib: Option<u32>,
+    pub parallelism: Option<u32>,
+    pub block_size: Option<u32>,
+    pub key_length: Option<usize>,
+    pub salt: Option<String>,
+}
+
+fn check_limit(name: &str, value: u64, max: u64) -> Result<()> {
+    if value > max {
+        return Err(anyhow!("{} {} exceeds the server maximum of {}", name, value, max));
+    }
+    Ok(())
+}
+
+fn check_scrypt_params(params: &scrypt::Params) -> Result<()> {
+    check_limit("scrypt log2(N)", params.log_n() as u64, MAX_SCRYPT_LOG_N as u64)?;
+    check_limit("scrypt parallelism", params.p() as u64, MAX_SCRYPT_PARALLELISM as u64)?;
+    check_limit("scrypt memory", 128 * params.r() as u64 * (1u64 << params.log_n()), MAX_SCRYPT_MEMORY)
+}
+
+fn check_argon2_params(params: &argon2::Params) -> Result<()> {
+    check_limit("Argon2 memory (KiB)", params.m_cost() as u64, MAX_ARGON2_MEMORY_KIB as u64)?;
+    check_limit("Argon2 iterations", params.t_cost() as u64, MAX_ARGON2_ITERATIONS as u64)?;
+    check_limit("Argon2 parallelism", params.p_cost() as u64, MAX_ARGON2_PARALLELISM as u64)
+}
+
+fn phc_salt(salt: Option<&str>) -> Result<SaltString> {
+    let bytes = match salt {
+        Some(salt) => salt.as_bytes().to_vec(),
+        None => rand::random::<[u8; 16]>().to_vec(),
+    };
+    SaltString::encode_b64(&bytes).map_err(|e| anyhow!("Invalid salt: {}", e))
+}
+
+fn argon2_algorithm(name: &str) -> Option<argon2::Algorithm> {
+    match name {
+        "argon2id" => Some(argon2::Algorithm::Argon2id),
+        "argon2i" => Some(argon2::Algorithm::Argon2i),
+        "argon2d" => Some(argon2::Algorithm::Argon2d),
+        _ => None,
+    }
+}
+
+fn pbkdf2_algorithm(name: &str) -> Option<pbkdf2::Algorithm> {
+    match name {
+        // PHC writes PBKDF2-HMAC-SHA1 as plain "pbkdf2"
+        "pbkdf2" | "pbkdf2-sha1" => Some(pbkdf2::Algorithm::Pbkdf2Sha1),
+        "pbkdf2-sha256" => Some(pbkdf2::Algorithm::Pbkdf2Sha256),
+        "pbkdf2-sha512" => Some(pbkdf2::Algorithm::Pbkdf2Sha512),
+        _ => None,
+    }
+}
+
+fn bcrypt_hash(password: &str, params: &PasswordParams) -> Result<String> {
+    let cost = params.cost.unwrap_or(bcrypt::DEFAULT_COST);
+    check_limit("bcrypt cost", cost as u64, MAX_BCRYPT_COST as u64)?;
+    let salt: [u8; 16] = match &params.salt {
+        Some(salt) => salt
+            .as_bytes()
+            .try_into()
+            .map_err(|_| anyhow!("bcrypt salt must be exactly 16 bytes"))?,
+        None => rand::random(),
+    };
+    Ok(bcrypt::hash_with_salt(password, cost, salt)?.format_for_version(bcrypt::Version::TwoB))
+}
+
+// MD5-crypt is deprecated upstream but still needed to reproduce legacy hashes
+#[allow(deprecated)]
+fn crypt_hash(password: &str, algorithm: &str, params: &PasswordParams) -> Result<String> {
+    let rounds = params.cost;
+    if let Some(rounds) = rounds {
+        check_limit("crypt rounds", rounds as u64, MAX_CRYPT_ROUNDS as u64)?;
+    }
+    let setup = pwhash::HashSetup { salt: params.salt.as_deref(), rounds };
+    let hash = match algorithm {
+        "md5-crypt" => pwhash::md5_crypt::hash_with(setup, password)?,
+        "sha256-crypt" => pwhash::sha256_crypt::hash_with(setup, password)?,
+        _ => pwhash::sha512_crypt::hash_with(setup, password)?,
+    };
+    Ok(hash)
+}
+
+/// Hash a password, emitting a PHC string for Argon2, scrypt and PBKDF2 and MCF for bcrypt and crypt.
+pub fn password_hash(password: &str, algorithm: &str, params: &PasswordParams) -> Result<String> {
+    let algorithm = algorithm.trim().to_lowercase();
+    let salt = params.salt.as_deref();
+
+    if let Some(variant) = argon2_algorithm(&algorithm) {
+        let argon2_params = argon2::Params::new(
+            params.memory_kib.unwrap_or(argon2::Params::DEFAULT_M_COST),
+            params.cost.unwrap_or(argon2::Params::DEFAULT_T_COST),
+            params.parallelism.unwrap_or(argon2::Params::DEFAULT_P_COST),
+            Some(params.key_length.unwrap_or(argon2::Params::DEFAULT_OUTPUT_LEN)),
+        )
+        .map_err(|e| anyhow!("Invalid Argon2 parameters: {}", e))?;
+        check_argon2_params(&argon2_params)?;
+        let argon2 = Argon2::new(variant, argon2::Version::V0x13, argon2_params);
+        return Ok(argon2.hash_password(password.as_bytes(), &phc_salt(salt)?)?.to_string());
+    }
+
+    if let Some(variant) = pbkdf2_algorithm(&algorithm) {
+        let pbkdf2_params = pbkdf2::Params {
+            rounds: params.cost.unwrap_or(600_000),
+            output_length: params.key_length.unwrap_or(32),
+        };
+        check_limit("PBKDF2 iterations", pbkdf2_params.rounds as u64, MAX_PBKDF2_ITERATIONS as u64)?;
+        let salt = phc_salt(salt)?;
+        let hash = Pbkdf2.hash_password_customized(password.as_bytes(), Some(variant.ident()), None, pbkdf2_params, &salt)?;
+        return Ok(hash.to_string());
+    }
+
+    match algorithm.as_str() {
+        "scrypt" => {
+            let log_n = params.cost.unwrap_or(scrypt::Params::RECOMMENDED_LOG_N as u32);
+            let scrypt_params = scrypt::Params::new(
+                u8::try_from(log_n).map_err(|_| anyhow!("scrypt log2(N) {} is out of range", log_n))?,
+                params.block_size.unwrap_or(scrypt::Params::RECOMMENDED_R),
+                params.parallelism.unwrap_or(scrypt::Params::RECOMMENDED_P),
+                params.key_length.unwrap_or(scrypt::Params::RECOMMENDED_LEN),
+            )
+            .map_err(|e| anyhow!("Invalid scrypt parameters: {}", e))?;
+            check_scrypt_params(&scrypt_params)?;
+            let salt = phc_salt(salt)?;
+            let hash = Scrypt.hash_password_customized(password.as_bytes(), None, None, scrypt_params, &salt)?;
+            Ok(hash.to_string())
+        }
+        "bcrypt" => bcrypt_hash(password, params),
+        "md5-crypt" | "sha256-crypt" | "sha512-crypt" => crypt_hash(password, &algorithm, params),
+        _ => Err(anyhow!(
+            "Unknown algorithm: {} (expected bcrypt, scrypt, argon2id, argon2i, argon2d, pbkdf2-sha1, pbkdf2-sha256, pbkdf2-sha512, md5-crypt, sha256-crypt or sha512-crypt)",
+            algorithm
+        )),
+    }
+}
+
+fn crypt_rounds(hash: &str) -> Option<u32> {
+    hash.get(3..)?.strip_prefix("rounds=")?.split('$').next()?.parse().ok()
+}
+
+fn verify_phc(password: &str, hash: &str) -> Result<bool> {
+    let parsed = PasswordHash::new(hash).map_err(|e| anyhow!("Unrecognised password hash: {}", e))?;
+    let algorithm = parsed.algorithm.as_str();
+
+    let result = if argon2_algorithm(algorithm).is_some() {
+        check_argon2_params(&argon2::Params::try_from(&parsed)?)?;
+        Argon2::default().verify_password(password.as_bytes(), &parsed)
+    } else if pbkdf2_algorithm(algorithm).is_some() {
+        let rounds = pbkdf2::Params::try_from(&parsed)?.rounds;
+        check_limit("PBKDF2 iterations", rounds as u64, MAX_PBKDF2_ITERATIONS as u64)?;
+        Pbkdf2.verify_password(password.as_bytes(), &parsed)
+    } else if algorithm == "scrypt" {
+        check_scrypt_params(&scrypt::Params::try_from(&parsed)?)?;
+        Scrypt.verify_password(password.as_bytes(), &parsed)
+    } else {
+        return Err(anyhow!("Unsupported PHC algorithm: {}", algorithm));
+    };
+
+    match result {
+        Ok(()) => Ok(true),
+        Err(password_hash::Error::Password) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Check a password against a bcrypt, crypt or PHC hash, enforcing the same cost limits as hashing.
+pub fn password_verify(password: &str, hash: &str) -> Result<String> {
+    let hash = hash.trim();
+    let matches = if hash.starts_with("$2") {
+        let cost: u32 = hash
+            .split('$')
+            .nth(2)
+            .and_then(|cost| cost.parse().ok())
+            .ok_or_else(|| anyhow!("Invalid bcrypt hash"))?;
+        check_limit("bcrypt cost", cost as u64, MAX_BCRYPT_COST as u64)?;
+        bcrypt::verify(password, hash)?
+    } else if hash.starts_with("$1$") {
+        pwhash::md5_crypt::verify(password, hash)
+    } else if hash.starts_with("$5$") || hash.starts_with("$6$") {
+        if let Some(rounds) = crypt_rounds(hash) {
+            check_limit("crypt rounds", rounds as u64, MAX_CRYPT_ROUNDS as u64)?;
+        }
+        if hash.starts_with("$5$") {
+            pwhash::sha256_crypt::verify(password, hash)
+        } else {
+            pwhash::sha512_crypt::verify(password, hash)
+        }
+    } else {
+        verify_phc(password, hash)?
+    };
+
+    Ok(if matches { "Valid" } else { "Invalid" }.to_string())
+}
+
+/// Inputs shared by the raw key derivation functions; the password or input key material is the operation input.
+#[derive(Debug, Clone, Copy)]
+pub struct KdfParams<'a> {
+    pub salt: &'a str,
+    pub salt_format: &'a str,
+    /// Derived key length in bytes
+    pub length: usize,
+    pub input_format: &'a str,
+    pub output_format: &'a str,
+}
+
+impl KdfParams<'_> {
+    fn input(&self, input: &str) -> Result<Vec<u8>> {
+        parse_bytes(input, self.input_format)
+    }
+
+    fn salt(&self) -> Result<Vec<u8>> {
+        parse_bytes(self.salt, self.salt_format)
+    }
+
+    fn length(&self) -> Result<usize> {
+        if self.length == 0 || self.length > MAX_DERIVED_KEY_LENGTH {
+            return Err(anyhow!("Key length must be between 1 and {} bytes", MAX_DERIVED_KEY_LENGTH));
+        }
+        Ok(self.length)
+    }
+
+    fn output(&self) -> Result<Vec<u8>> {
+        Ok(vec![0; self.length()?])
+    }
+}
+
+#[derive(Clone, Copy)]
+enum KdfHash {
+    Md5,
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl KdfHash {
+    fn from_name(name: &str) -> Result<Self> {
+        let name = name.trim().to_lowercase();
+        match name.trim_start_matches("hmac-").replace('-', "").as_str() {
+            "md5" => Ok(KdfHash::Md5),
+            "sha1" => Ok(KdfHash::Sha1),
+            "sha256" => Ok(KdfHash::Sha256),
+            "sha384" => Ok(KdfHash::Sha384),
+            "sha512" => Ok(KdfHash::Sha512),
+            _ => Err(anyhow!("Unknown hash: {} (expected md5, sha1, sha256, sha384 or sha512)", name)),
+        }
+    }
+}
+
+fn hkdf_with<D: Digest + BlockSizeUser + Clone>(ikm: &[u8], salt: &[u8], info: &[u8], mode: &str, okm: &mut Vec<u8>) -> Result<()> {
+    let salt = (!salt.is_empty()).then_some(salt);
+    match mode {
+        "extract" => *okm = SimpleHkdf::<D>::extract(salt, ikm).0.to_vec(),
+        "expand" => SimpleHkdf::<D>::from_prk(ikm)
+            .map_err(|_| anyhow!("Pseudorandom key must be at least {} bytes", <D as Digest>::output_size()))?
+            .expand(info, okm)
+            .map_err(|_| anyhow!("HKDF output is limited to {} bytes", 255 * <D as Digest>::output_size()))?,
+        "extract_expand" | "full" => SimpleHkdf::<D>::new(salt, ikm)
+            .expand(info, okm)
+            .map_err(|_| anyhow!("HKDF output is limited to {} bytes", 255 * <D as Digest>::output_size()))?,
+        other => return Err(anyhow!("Unknown HKDF mode: {} (expected extract_expand, extract or expand)", other)),
+    }
+    Ok(())
+}
+
+/// HKDF (RFC 5869). "extract" returns the PRK and ignores the length; "expand" treats the input as the PRK.
+pub fn hkdf_derive(input: &str, hash: &str, mode: &str, info: &str, info_format: &str, params: &KdfParams) -> Result<String> {
+    let ikm = params.input(input)?;
+    let salt = params.salt()?;
+    let info = parse_bytes(info, info_format)?;
+    let mode = mode.trim().to_lowercase();
+    let mut okm = params.output()?;
+    match KdfHash::from_name(hash)? {
+        KdfHash::Md5 => hkdf_with::<Md5>(&ikm, &salt, &info, &mode, &mut okm)?,
+        KdfHash::Sha1 => hkdf_with::<Sha1>(&ikm, &salt, &info, &mode, &mut okm)?,
+        KdfHash::Sha256 => hkdf_with::<Sha256>(&ikm, &salt, &info, &mode, &mut okm)?,
+        KdfHash::Sha384 => hkdf_with::<Sha384>(&ikm, &salt, &info, &mode, &mut okm)?,
+        KdfHash::Sha512 => hkdf_with::<Sha512>(&ikm, &salt, &info, &mode, &mut okm)?,
+    }
+    format_bytes(&okm, params.output_format)
+}
+
+/// PBKDF2 (RFC 8018) with an HMAC PRF over raw bytes, for callers that feed the key straight into a cipher.
+pub fn pbkdf2_raw(password: &[u8], salt: &[u8], prf: &str, iterations: u32, length: usize) -> Result<Vec<u8>> {
+    if iterations == 0 {
+        return Err(anyhow!("PBKDF2 needs at least one iteration"));
+    }
+    check_limit("PBKDF2 iterations", iterations as u64, MAX_PBKDF2_ITERATIONS as u64)?;
+    let run = match KdfHash::from_name(prf)? {
+        KdfHash::Md5 => pbkdf2::pbkdf2::<SimpleHmac<Md5>>,
+        KdfHash::Sha1 => pbkdf2::pbkdf2::<SimpleHmac<Sha1>>,
+        KdfHash::Sha256 => pbkdf2::pbkdf2::<SimpleHmac<Sha256>>,
+        KdfHash::Sha384 => pbkdf2::pbkdf2::<SimpleHmac<Sha384>>,
+        KdfHash::Sha512 => pbkdf2::pbkdf2::<SimpleHmac<Sha512>>,
+    };
+    let mut key = vec![0; length];
+    run(password, salt, iterations, &mut key).map_err(|e| anyhow!("PBKDF2 failed: {}", e))?;
+    Ok(key)
+}
+
+/// PBKDF2 (RFC 8018) with an HMAC PRF, returning the raw derived key.
+pub fn pbkdf2_derive(input: &str, prf: &str, iterations: u32, params: &KdfParams) -> Result<String> {
+    let key = pbkdf2_raw(&params.input(input)?, &params.salt()?, prf, iterations, params.length()?)?;
+    format_bytes(&key, params.output_format)
+}
+
+fn bytes_to_key_with<D: Digest>(data: &[u8], salt: &[u8], iterations: u32, length: usize) -> Vec<u8> {
+    let mut derived = Vec::with_capacity(length);
+    let mut block = Vec::new();
+    while derived.len() < length {
+        let mut hasher = D::new();
+        hasher.update(&block);
+        hasher.update(data);
+        hasher.update(salt);
+        block = hasher.finalize().to_vec();
+        for _ in 1..iterations {
+            block = D::digest(&block).to_vec();
+        }
+        derived.extend_from_slice(&block);
+    }
+    derived.truncate(length);
+    derived
+}
+
+/// OpenSSL's EVP_BytesToKey: derive `key_length + iv_length` bytes from a password and an optional 8-byte salt.
+pub fn evp_bytes_to_key_raw(password: &[u8], salt: &[u8], hash: &str, iterations: u32, key_length: usize, iv_length: usize) -> Result<(Vec<u8>, Vec<u8>)> {
+    if !salt.is_empty() && salt.len() != 8 {
+        return Err(anyhow!("EVP_BytesToKey salt must be empty or exactly 8 bytes"));
+    }
+    if iterations == 0 {
+        return Err(anyhow!("EVP_BytesToKey needs at least one iteration"));
+    }
+    check_limit("EVP_BytesToKey iterations", iterations as u64, MAX_EVP_ITERATIONS as u64)?;
+    let length = key_length
+        .checked_add(iv_length)
+        .filter(|&length| length <= MAX_DERIVED_KEY_LENGTH)
+        .ok_or_else(|| anyhow!("Key and IV length must not exceed {} bytes", MAX_DERIVED_KEY_LENGTH))?;
+    let mut derived = match KdfHash::from_name(hash)? {
+        KdfHash::Md5 => bytes_to_key_with::<Md5>(password, salt, iterations, length),
+        KdfHash::Sha1 => bytes_to_key_with::<Sha1>(password, salt, iterations, length),
+        KdfHash::Sha256 => bytes_to_key_with::<Sha256>(password, salt, iterations, length),
+        KdfHash::Sha384 => bytes_to_key_with::<Sha384>(password, salt, iterations, length),
+        KdfHash::Sha512 => bytes_to_key_with::<Sha512>(password, salt, iterations, length),
+    };
+    let iv = derived.split_off(key_length);
+    Ok((derived, iv))
+}
+
+/// EVP_BytesToKey as printed by `openssl enc -P`: one `key=` line and, when an IV is requested, one `iv=` line.
+pub fn evp_bytes_to_key(input: &str, hash: &str, iterations: u32, iv_length: usize, params: &KdfParams) -> Result<String> {
+    let (key, iv) = evp_bytes_to_key_raw(&params.input(input)?, &params.salt()?, hash, iterations, params.length()?, iv_length)?;
+    let mut lines = vec![format!("key={}", format_bytes(&key, params.output_format)?)];
+    if !iv.is_empty() {
+        lines.push(format!("iv={}", format_bytes(&iv, params.output_format)?));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Raw scrypt output; `log_n` is log2 of the CPU/memory cost N.
+pub fn scrypt_derive(input: &str, log_n: u8, block_size: u32, parallelism: u32, params: &KdfParams) -> Result<String> {
+    let mut key = params.output()?;
+    let scrypt_params = scrypt::Params::new(log_n, block_size, parallelism, key.len())
+        .map_err(|e| anyhow!("Invalid scrypt parameters: {}", e))?;
+    check_scrypt_params(&scrypt_params)?;
+    scrypt::scrypt(&params.input(input)?, &params.salt()?, &scrypt_params, &mut key).map_err(|e| anyhow!("scrypt failed: {}", e))?;
+    format_bytes(&key, params.output_format)
+}
+
+/// Raw Argon2 (version 0x13) output for argon2id, argon2i or argon2d.
+pub fn argon2_derive(input: &str, variant: &str, memory_kib: u32, iterations: u32, parallelism: u32, params: &KdfParams) -> Result<String> {
+    let variant = variant.trim().to_lowercase();
+    let algorithm = argon2_algorithm(&variant).ok_or_else(|| anyhow!("Unknown Argon2 variant: {} (expected argon2id, argon2i or argon2d)", variant))?;
+    let mut key = params.output()?;
+    let argon2_params = argon2::Params::new(memory_kib, iterations, parallelism, Some(key.len()))
+        .map_err(|e| anyhow!("Invalid Argon2 parameters: {}", e))?;
+    check_argon2_params(&argon2_params)?;
+    Argon2::new(algorithm, argon2::Version::V0x13, argon2_params)
+        .hash_password_into(&params.input(input)?, &params.salt()?, &mut key)
+        .map_err(|e| anyhow!("Argon2 failed: {}", e))?;
+    format_bytes(&key, params.output_format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fast_params(cost: u32) -> PasswordParams {
+        PasswordParams { cost: Some(cost), memory_kib: Some(64), parallelism: Some(1), ..Default::default() }
+    }
+
+    #[test]
+    fn test_phc_roundtrip() {
+        let cases = [
+            ("argon2id", 1, "$argon2id$v=19$m=64,t=1,p=1$"),
+            ("argon2i", 1, "$argon2i$v=19$m=64,t=1,p=1$"),
+            ("argon2d", 1, "$argon2d$v=19$m=64,t=1,p=1$"),
+            ("scrypt", 4, "$scrypt$ln=4,r=8,p=1$"),
+            ("pbkdf2-sha256", 1000, "$pbkdf2-sha256$i=1000,l=32$"),
+            ("pbkdf2-sha1", 1000, "$pbkdf2$i=1000,l=32$"),
+        ];
+        for (algorithm, cost, prefix) in cases {
+            let hash = password_hash("hunter2", algorithm, &fast_params(cost)).unwrap();
+            assert!(hash.starts_with(prefix), "{}", hash);
+            assert_eq!(password_verify("hunter2", &hash).unwrap(), "Valid", "{}", algorithm);
+            assert_eq!(password_verify("hunter3", &hash).unwrap(), "Invalid", "{}", algorithm);
+        }
+    }
+
+    #[test]
+    fn test_known_hashes() {
+        // PBKDF2-HMAC-SHA256("password", "salt", 1 iteration, 32 bytes)
+        let params = PasswordParams { cost: Some(1), salt: Some("salt".into()), ..Default::default() };
+        let hash = password_hash("password", "pbkdf2-sha256", &params).unwrap();
+        let parsed = PasswordHash::new(&hash).unwrap();
+        assert_eq!(
+            hex::encode(parsed.hash.unwrap().as_bytes()),
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+        );
+
+        let sha512 = "$6$G/gkPn17kHYo0gTF$xhDFU0QYExdMH2ghOWKrrVtu1BuTpNMSJURCXk43.EYekmK8iwV6RNqftUUC8mqDel1J7m3JEbUkbu4YyqSyv/";
+        assert_eq!(password_verify("test", sha512).unwrap(), "Valid");
+        let bcrypt = "$2b$04$EGdrhbKUv8Oc9vGiXX0HQOxSg445d458Muh7DAHskb6QbtCvdxcie";
+        assert_eq!(password_verify("correctbatteryhorsestapler", bcrypt).unwrap(), "Valid");
+        assert_eq!(password_verify("wrong", bcrypt).unwrap(), "Invalid");
+    }
+
+    #[test]
+    fn test_crypt_and_bcrypt_roundtrip() {
+        for algorithm in ["bcrypt", "md5-crypt", "sha256-crypt", "sha512-crypt"] {
+            let cost = if algorithm == "bcrypt" { 4 } else { 1000 };
+            let hash = password_hash("s3cret", algorithm, &PasswordParams { cost: Some(cost), ..Default::default() }).unwrap();
+            assert_eq!(password_verify("s3cret", &hash).unwrap(), "Valid", "{}", hash);
+            assert_eq!(password_verify("s3cre7", &hash).unwrap(), "Invalid", "{}", hash);
+        }
+        let salted = password_hash("s3cret", "sha256-crypt", &PasswordParams { salt: Some("saltstring".into()), ..Default::default() }).unwrap();
+        assert!(salted.starts_with("$5$saltstring$"));
+    }
+
+    #[test]
+    fn test_cost_limits() {
+        assert!(password_hash("x", "bcrypt", &PasswordParams { cost: Some(20), ..Default::default() }).is_err());
+        assert!(password_hash("x", "argon2id", &PasswordParams { memory_kib: Some(1 << 22), ..Default::default() }).is_err());
+        assert!(password_hash("x", "scrypt", &PasswordParams { cost: Some(22), ..Default::default() }).is_err());
+        assert!(password_hash("x", "pbkdf2-sha512", &fast_params(10_000_000)).is_err());
+        // A hostile hash must be rejected before any work is done
+        assert!(password_verify("x", "$argon2id$v=19$m=4194304,t=3,p=1$c2FsdHNhbHQ$aGFzaGhhc2hoYXNoaGFzaA").is_err());
+        assert!(password_verify("x", "$2b$31$bvIG6Nmid91Mu9RcmmWZfO5HJIMCT8riNW0hEp8f6/FuA2/mHZFpe").is_err());
+        assert!(password_verify("x", "$6$rounds=999999999$salt$abc").is_err());
+    }
+
+    fn kdf_params<'a>(salt: &'a str, length: usize) -> KdfParams<'a> {
+        KdfParams { salt, salt_format: "hex", length, input_format: "utf8", output_format: "hex" }
+    }
+
+    #[test]
+    fn test_hkdf_rfc5869() {
+        let ikm = "0b".repeat(22);
+        let params = KdfParams { input_format: "hex", ..kdf_params("000102030405060708090a0b0c", 42) };
+        let okm = "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865";
+        assert_eq!(hkdf_derive(&ikm, "sha256", "extract_expand", "f0f1f2f3f4f5f6f7f8f9", "hex", &params).unwrap(), okm);
+        let prk = hkdf_derive(&ikm, "sha256", "extract", "", "hex", &params).unwrap();
+        assert_eq!(prk, "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+        assert_eq!(hkdf_derive(&prk, "sha256", "expand", "f0f1f2f3f4f5f6f7f8f9", "hex", &params).unwrap(), okm);
+        assert!(hkdf_derive(&ikm, "sha256", "full", "", "hex", &KdfParams { length: 255 * 32 + 1, ..params }).is_err());
+    }
+
+    #[test]
+    fn test_pbkdf2_and_scrypt_vectors() {
+        // RFC 6070 and RFC 7914
+        let salt = hex::encode("salt");
+        assert_eq!(pbkdf2_derive("password", "sha1", 2, &kdf_params(&salt, 20)).unwrap(), "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957");
+        assert_eq!(
+            pbkdf2_derive("password", "hmac-sha256", 1, &KdfParams { output_format: "base64", ..kdf_params(&salt, 32) }).unwrap(),
+            "Eg+2z/z4syxD5yJSVsT4N6hlSMkszDVICAWYfLcL4Xs="
+        );
+        assert!(pbkdf2_derive("password", "sha256", 10_000_000, &kdf_params(&salt, 32)).is_err());
+        assert_eq!(
+            scrypt_derive("", 4, 1, 1, &kdf_params("", 64)).unwrap(),
+            "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+        );
+        assert!(scrypt_derive("", 4, 1, 1, &kdf_params("", 0)).is_err());
+    }
+
+    #[test]
+    fn test_evp_bytes_to_key() {
+        // openssl enc -aes-256-cbc -md md5 -pass pass:password -S 0102030405060708 -P
+        let derived = evp_bytes_to_key("password", "md5", 1, 16, &kdf_params("0102030405060708", 32)).unwrap();
+        assert_eq!(derived, "key=e7b0971e52ca5cc8d0539fb3412f6316f7ba2e6ee293d9f3457b99436b51ce02\niv=8d450e2ed75a84a923d4eac9fe49226b");
+        let unsalted = evp_bytes_to_key("password", "sha256", 1, 16, &kdf_params("", 16)).unwrap();
+        assert_eq!(unsalted, "key=5e884898da28047151d0e56f8dc62927\niv=73603d0d6aabbdd62a11ef721d1542d8");
+        assert!(evp_bytes_to_key("password", "md5", 1, 16, &kdf_params("0102", 32)).is_err());
+        assert!(evp_bytes_to_key("password", "md5", 1, usize::MAX, &kdf_params("", 32)).is_err());
+    }
+
+    #[test]
+    fn test_argon2_raw_matches_phc() {
+        let params = PasswordParams { salt: Some("somesalt".into()), key_length: Some(24), ..fast_params(2) };
+        let hash = password_hash("password", "argon2i", &params).unwrap();
+        let expected = hex::encode(PasswordHash::new(&hash).unwrap().hash.unwrap().as_bytes());
+        let derived = argon2_derive("password", "argon2i", 64, 2, 1, &kdf_params(&hex::encode("somesalt"), 24)).unwrap();
+        assert_eq!(derived, expected);
+        assert!(argon2_derive("password", "argon2x", 64, 2, 1, &kdf_params(&hex::encode("somesalt"), 24)).is_err());
+    }
+}
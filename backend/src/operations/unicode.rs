@@ -0,0 +1,281 @@
+use anyhow::{anyhow, Result};
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::UnicodeNormalization;
+use unicode_script::{Script, UnicodeScript};
+use unicode_security::{skeleton, MixedScript};
+
+const ZERO_WIDTH: &[char] = &[
+    '\u{00ad}', '\u{180e}', '\u{200b}', '\u{200c}', '\u{200d}', '\u{2060}', '\u{feff}',
+];
+
+const BIDI_CONTROLS: &[char] = &[
+    '\u{061c}', '\u{200e}', '\u{200f}', '\u{202a}', '\u{202b}', '\u{202c}', '\u{202d}', '\u{202e}',
+    '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}',
+];
+
+pub fn unicode_normalize(input: &str, form: &str) -> Result<String> {
+    let result = match form.to_uppercase().as_str() {
+        "NFC" => input.nfc().collect(),
+        "NFD" => input.nfd().collect(),
+        "NFKC" => input.nfkc().collect(),
+        "NFKD" => input.nfkd().collect(),
+        _ => return Err(anyhow!("Unknown normalization form: {} (expected NFC, NFD, NFKC or NFKD)", form)),
+    };
+    Ok(result)
+}
+
+fn category_abbreviation(category: GeneralCategory) -> &'static str {
+    match category {
+        GeneralCategory::UppercaseLetter => "Lu",
+        GeneralCategory::LowercaseLetter => "Ll",
+        GeneralCategory::TitlecaseLetter => "Lt",
+        GeneralCategory::ModifierLetter => "Lm",
+        GeneralCategory::OtherLetter => "Lo",
+        GeneralCategory::NonspacingMark => "Mn",
+        GeneralCategory::SpacingMark => "Mc",
+        GeneralCategory::EnclosingMark => "Me",
+        GeneralCategory::DecimalNumber => "Nd",
+        GeneralCategory::LetterNumber => "Nl",
+        GeneralCategory::OtherNumber => "No",
+        GeneralCategory::ConnectorPunctuation => "Pc",
+        GeneralCategory::DashPunctuation => "Pd",
+        GeneralCategory::OpenPunctuation => "Ps",
+        GeneralCategory::ClosePunctuation => "Pe",
+        GeneralCategory::InitialPunctuation => "Pi",
+        GeneralCategory::FinalPunctuation => "Pf",
+        GeneralCategory::OtherPunctuation => "Po",
+        GeneralCategory::MathSymbol => "Sm",
+        GeneralCategory::CurrencySymbol => "Sc",
+        GeneralCategory::ModifierSymbol => "Sk",
+        GeneralCategory::OtherSymbol => "So",
+        GeneralCategory::SpaceSeparator => "Zs",
+        GeneralCategory::LineSeparator => "Zl",
+        GeneralCategory::ParagraphSeparator => "Zp",
+        GeneralCategory::Control => "Cc",
+        GeneralCategory::Format => "Cf",
+        GeneralCategory::Surrogate => "Cs",
+        GeneralCategory::PrivateUse => "Co",
+        _ => "Cn",
+    }
+}
+
+fn char_name(ch: char) -> String {
+    match unicode_names2::name(ch) {
+        Some(name) => name.to_string(),
+        None => match get_general_category(ch) {
+            GeneralCategory::Control => "<control>".to_string(),
+            GeneralCategory::PrivateUse => "<private use>".to_string(),
+            _ => "<unassigned>".to_string(),
+        },
+    }
+}
+
+pub fn unicode_inspect(input: &str) -> Result<String> {
+    let lines: Vec<String> = input
+        .chars()
+        .map(|ch| {
+            let mut utf8 = [0u8; 4];
+            let mut utf16 = [0u16; 2];
+            let utf8: Vec<String> = ch.encode_utf8(&mut utf8).bytes().map(|b| format!("{:02x}", b)).collect();
+            let utf16: Vec<String> = ch.encode_utf16(&mut utf16).iter().map(|u| format!("{:04x}", u)).collect();
+            format!(
+                "U+{:04X}\t{}\t{}\t{}\tUTF-8: {}\tUTF-16: {}",
+                ch as u32,
+                ch.escape_debug(),
+                category_abbreviation(get_general_category(ch)),
+                char_name(ch),
+                utf8.join(" "),
+                utf16.join(" ")
+            )
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+pub fn unicode_escape(input: &str, style: &str, escape_all: bool) -> Result<String> {
+    let style = style.to_lowercase();
+    if !matches!(style.as_str(), "js" | "rust" | "html" | "percent") {
+        return Err(anyhow!("Unknown escape style: {} (expected js, rust, html or percent)", style));
+    }
+
+    let mut result = String::with_capacity(input.len());
+    for ch in input.chars() {
+        if ch.is_ascii() && !escape_all {
+            result.push(ch);
+            continue;
+        }
+        match style.as_str() {
+            "js" => {
+                let mut buf = [0u16; 2];
+                for unit in ch.encode_utf16(&mut buf) {
+                    result.push_str(&format!("\\u{:04X}", unit));
+                }
+            }
+            "rust" => result.push_str(&format!("\\u{{{:X}}}", ch as u32)),
+            "html" => result.push_str(&format!("&#x{:X};", ch as u32)),
+            _ => {
+                let mut buf = [0u16; 2];
+                for unit in ch.encode_utf16(&mut buf) {
+                    result.push_str(&format!("%u{:04X}", unit));
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Unescape `\uXXXX` (with surrogate pairs), `\u{...}`, `&#x...;` and `%uXXXX` sequences.
+pub fn unicode_unescape(input: &str) -> Result<String> {
+    let escape_regex = regex::Regex::new(
+        r"\\u\{([0-9A-Fa-f]{1,6})\}|&#[xX]([0-9A-Fa-f]{1,6});|(?:\\u|%u)([0-9A-Fa-f]{4})",
+    )
+    .unwrap();
+
+    let mut result = String::with_capacity(input.len());
+    let mut pending_high: Option<u16> = None;
+    let mut last = 0;
+
+    for caps in escape_regex.captures_iter(input) {
+        let m = caps.get(0).unwrap();
+        if m.start() != last {
+            if let Some(high) = pending_high.take() {
+                return Err(anyhow!("Unpaired surrogate \\u{:04X}", high));
+            }
+            result.push_str(&input[last..m.start()]);
+        }
+        last = m.end();
+
+        if let Some(unit) = caps.get(3) {
+            let unit = u16::from_str_radix(unit.as_str(), 16)?;
+            match (pending_high.take(), unit) {
+                (None, 0xd800..=0xdbff) => pending_high = Some(unit),
+                (Some(high), 0xdc00..=0xdfff) => {
+                    result.extend(char::decode_utf16([high, unit]).map(|c| c.unwrap()));
+                }
+                (Some(high), _) => return Err(anyhow!("Unpaired surrogate \\u{:04X}", high)),
+                (None, _) => {
+                    result.push(char::from_u32(unit as u32).ok_or_else(|| anyhow!("Unpaired surrogate \\u{:04X}", unit))?)
+                }
+            }
+            continue;
+        }
+
+        if let Some(high) = pending_high.take() {
+            return Err(anyhow!("Unpaired surrogate \\u{:04X}", high));
+        }
+        let hex = caps.get(1).or_else(|| caps.get(2)).unwrap().as_str();
+        let code_point = u32::from_str_radix(hex, 16)?;
+        result.push(char::from_u32(code_point).ok_or_else(|| anyhow!("Invalid code point U+{:X}", code_point))?);
+    }
+
+    if let Some(high) = pending_high {
+        return Err(anyhow!("Unpaired surrogate \\u{:04X}", high));
+    }
+    result.push_str(&input[last..]);
+    Ok(result)
+}
+
+fn ascii_lookalike(ch: char) -> Option<String> {
+    if ch.is_ascii() {
+        return None;
+    }
+    let lookalike: String = skeleton(ch.encode_utf8(&mut [0u8; 4])).collect();
+    if lookalike.is_empty() || !lookalike.chars().all(|c| c.is_ascii_graphic()) {
+        return None;
+    }
+    Some(lookalike)
+}
+
+/// Flag homoglyphs, zero-width and bidi control characters, and words mixing scripts.
+pub fn detect_unicode_spoofing(input: &str) -> Result<String> {
+    let mut findings = Vec::new();
+    let mut lookalike_text = String::with_capacity(input.len());
+
+    for (index, ch) in input.chars().enumerate() {
+        let kind = if ZERO_WIDTH.contains(&ch) {
+            "zero-width character".to_string()
+        } else if BIDI_CONTROLS.contains(&ch) {
+            "bidirectional control character".to_string()
+        } else if get_general_category(ch) == GeneralCategory::Format {
+            "invisible formatting character".to_string()
+        } else if let Some(lookalike) = ascii_lookalike(ch) {
+            lookalike_text.push_str(&lookalike);
+            format!("homoglyph of '{}'", lookalike)
+        } else {
+            lookalike_text.push(ch);
+            continue;
+        };
+        findings.push(format!("Index {}: U+{:04X} {} ({})", index, ch as u32, char_name(ch), kind));
+    }
+
+    for word in input.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+        if word.is_single_script() {
+            continue;
+        }
+        let mut scripts: Vec<&str> = Vec::new();
+        for ch in word.chars() {
+            let script = ch.script();
+            if !matches!(script, Script::Common | Script::Inherited) && !scripts.contains(&script.full_name()) {
+                scripts.push(script.full_name());
+            }
+        }
+        findings.push(format!("Mixed scripts in '{}': {}", word, scripts.join(", ")));
+    }
+
+    if findings.is_empty() {
+        return Ok("No suspicious characters found".to_string());
+    }
+
+    findings.push(format!("Lookalike: {}", lookalike_text));
+    Ok(findings.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unicode_normalize() {
+        let decomposed = "e\u{0301}";
+        assert_eq!(unicode_normalize(decomposed, "NFC").unwrap(), "\u{e9}");
+        assert_eq!(unicode_normalize("\u{e9}", "nfd").unwrap(), decomposed);
+        assert_eq!(unicode_normalize("\u{fb01}", "NFKC").unwrap(), "fi");
+    }
+
+    #[test]
+    fn test_unicode_inspect() {
+        let result = unicode_inspect("\u{e9}\u{1f600}").unwrap();
+        assert!(result.contains("U+00E9\t\u{e9}\tLl\tLATIN SMALL LETTER E WITH ACUTE\tUTF-8: c3 a9\tUTF-16: 00e9"));
+        assert!(result.contains("UTF-8: f0 9f 98 80\tUTF-16: d83d de00"));
+    }
+
+    #[test]
+    fn test_unicode_escape_unescape() {
+        let input = "caf\u{e9} \u{1f600}";
+        assert_eq!(unicode_escape(input, "js", false).unwrap(), "caf\\u00E9 \\uD83D\\uDE00");
+        assert_eq!(unicode_escape(input, "rust", false).unwrap(), "caf\\u{E9} \\u{1F600}");
+        assert_eq!(unicode_escape(input, "html", false).unwrap(), "caf&#xE9; &#x1F600;");
+        assert_eq!(unicode_escape(input, "percent", false).unwrap(), "caf%u00E9 %uD83D%uDE00");
+        for style in ["js", "rust", "html", "percent"] {
+            let escaped = unicode_escape(input, style, true).unwrap();
+            assert_eq!(unicode_unescape(&escaped).unwrap(), input);
+        }
+        assert!(unicode_unescape("\\uD83D").is_err());
+        assert!(unicode_escape("abc", "bogus", false).is_err());
+        assert!(unicode_escape("", "bogus", false).is_err());
+    }
+
+    #[test]
+    fn test_detect_unicode_spoofing() {
+        let result = detect_unicode_spoofing("p\u{430}ypal.com").unwrap();
+        assert!(result.contains("U+0430 CYRILLIC SMALL LETTER A (homoglyph of 'a')"));
+        assert!(result.contains("Mixed scripts in 'p\u{430}ypal': Latin, Cyrillic"));
+        assert!(result.contains("Lookalike: paypal.com"));
+
+        let result = detect_unicode_spoofing("access\u{202e}\u{2066}level\u{200b}").unwrap();
+        assert!(result.contains("RIGHT-TO-LEFT OVERRIDE (bidirectional control character)"));
+        assert!(result.contains("ZERO WIDTH SPACE (zero-width character)"));
+
+        assert_eq!(detect_unicode_spoofing("example.com").unwrap(), "No suspicious characters found");
+    }
+}
@@ -4,6 +4,8 @@ use md5::Md5;
 use sha1::Sha1;
 use sha2::{Sha256, Sha512, Digest};
 use percent_encoding::{utf8_percent_encode, percent_decode_str, AsciiSet, CONTROLS};
+use std::collections::HashMap;
+use std::sync::OnceLock;
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global allocator.
 #[cfg(feature = "wee_alloc")]
@@ -52,29 +54,128 @@ pub fn url_decode(input: &str) -> Result<String, JsValue> {
         .map_err(|e| JsValue::from_str(&e.to_string()))
 }
 
+// HTML5 replacements for numeric references in the C1 control range (0x80..=0x9F)
+const C1_REPLACEMENTS: [u32; 32] = [
+    0x20ac, 0x81, 0x201a, 0x0192, 0x201e, 0x2026, 0x2020, 0x2021,
+    0x02c6, 0x2030, 0x0160, 0x2039, 0x0152, 0x8d, 0x017d, 0x8f,
+    0x90, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+    0x02dc, 0x2122, 0x0161, 0x203a, 0x0153, 0x9d, 0x017e, 0x0178,
+];
+
+// Longest HTML5 entity name, "CounterClockwiseContourIntegral;"
+const MAX_ENTITY_LENGTH: usize = 32;
+
+fn named_entities() -> &'static HashMap<&'static str, &'static str> {
+    static TABLE: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    TABLE.get_or_init(|| entities::ENTITIES.iter().map(|e| (&e.entity[1..], e.characters)).collect())
+}
+
+fn entity_names() -> &'static HashMap<char, &'static str> {
+    static TABLE: OnceLock<HashMap<char, &'static str>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut names: HashMap<char, &'static str> = HashMap::new();
+        for entity in entities::ENTITIES.iter().filter(|e| e.entity.ends_with(';')) {
+            let mut chars = entity.characters.chars();
+            let (Some(ch), None) = (chars.next(), chars.next()) else { continue };
+            // Prefer the shortest name, then lowercase (&amp; over &AMP;)
+            let rank = |name: &str| (name.len(), name.starts_with(|c: char| c.is_ascii_uppercase()));
+            let name = entity.entity;
+            names
+                .entry(ch)
+                .and_modify(|current| if rank(name) < rank(current) { *current = name })
+                .or_insert(name);
+        }
+        names
+    })
+}
+
+fn numeric_reference_char(code_point: u32) -> char {
+    match code_point {
+        0x80..=0x9f => char::from_u32(C1_REPLACEMENTS[(code_point - 0x80) as usize]).unwrap(),
+        0 => char::REPLACEMENT_CHARACTER,
+        _ => char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER),
+    }
+}
+
+// Decode the reference following an '&', returning the text and the number of bytes consumed
+fn decode_reference(reference: &str) -> Option<(String, usize)> {
+    if let Some(numeric) = reference.strip_prefix('#') {
+        let (prefix_len, radix) = if numeric.starts_with(['x', 'X']) { (1, 16) } else { (0, 10) };
+        let digits_len = numeric[prefix_len..].bytes().take_while(|b| (*b as char).is_digit(radix)).count();
+        if digits_len == 0 {
+            return None;
+        }
+        let digits = &numeric[prefix_len..prefix_len + digits_len];
+        let mut consumed = 1 + prefix_len + digits_len;
+        if reference[consumed..].starts_with(';') {
+            consumed += 1;
+        }
+        let code_point = u32::from_str_radix(digits, radix).unwrap_or(u32::MAX);
+        return Some((numeric_reference_char(code_point).to_string(), consumed));
+    }
+
+    let name_len = reference
+        .bytes()
+        .take(MAX_ENTITY_LENGTH)
+        .take_while(|b| b.is_ascii_alphanumeric())
+        .count();
+    let table = named_entities();
+    if reference[name_len..].starts_with(';') {
+        if let Some(characters) = table.get(&reference[..=name_len]) {
+            return Some((characters.to_string(), name_len + 1));
+        }
+    }
+    // Legacy entities such as "&amp" and "&copy" are recognised without a semicolon
+    (1..=name_len)
+        .rev()
+        .find_map(|len| table.get(&reference[..len]).map(|characters| (characters.to_string(), len)))
+}
+
 #[wasm_bindgen]
-pub fn html_encode(input: &str) -> String {
-    input
-        .chars()
-        .map(|c| match c {
-            '<' => "&lt;".to_string(),
-            '>' => "&gt;".to_string(),
-            '&' => "&amp;".to_string(),
-            '"' => "&quot;".to_string(),
-            '\'' => "&#x27;".to_string(),
-            _ => c.to_string(),
-        })
-        .collect()
+pub fn html_encode(input: &str, mode: &str) -> Result<String, JsValue> {
+    let mode = mode.to_lowercase();
+    if !matches!(mode.as_str(), "minimal" | "non_ascii" | "all") {
+        return Err(JsValue::from_str(&format!("Unknown HTML encode mode: {} (expected minimal, non_ascii or all)", mode)));
+    }
+
+    let mut result = String::with_capacity(input.len());
+    for c in input.chars() {
+        match (mode.as_str(), c) {
+            (_, '<') => result.push_str("&lt;"),
+            (_, '>') => result.push_str("&gt;"),
+            (_, '&') => result.push_str("&amp;"),
+            (_, '"') => result.push_str("&quot;"),
+            (_, '\'') => result.push_str("&#x27;"),
+            ("minimal", _) => result.push(c),
+            ("non_ascii", _) if c.is_ascii() => result.push(c),
+            ("non_ascii", _) => result.push_str(&format!("&#x{:x};", c as u32)),
+            _ => match entity_names().get(&c) {
+                Some(name) => result.push_str(name),
+                None => result.push_str(&format!("&#x{:x};", c as u32)),
+            },
+        }
+    }
+    Ok(result)
 }
 
 #[wasm_bindgen]
 pub fn html_decode(input: &str) -> Result<String, JsValue> {
-    let result = input
-        .replace("&lt;", "<")
-        .replace("&gt;", ">")
-        .replace("&amp;", "&")
-        .replace("&quot;", "\"")
-        .replace("&#x27;", "'");
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(pos) = rest.find('&') {
+        result.push_str(&rest[..pos]);
+        match decode_reference(&rest[pos + 1..]) {
+            Some((decoded, consumed)) => {
+                result.push_str(&decoded);
+                rest = &rest[pos + 1 + consumed..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[pos + 1..];
+            }
+        }
+    }
+    result.push_str(rest);
     Ok(result)
 }
 